@@ -0,0 +1,628 @@
+//! Agent Client Protocol (ACP) adapter: strict JSON-RPC 2.0 over stdio so
+//! ACP-capable editors (Zed and friends) can drive a session directly.
+//!
+//! This sits next to [`crate::rpc`] rather than inside it: ACP mandates
+//! `{"jsonrpc": "2.0", ...}` envelopes with `method`/`params`/`id`, which is
+//! a different wire shape from `rpc`'s line-delimited `{"type": ...}`
+//! protocol. Everything underneath is shared, though -- turn execution goes
+//! through the same [`AgentSession::run_text_with_abort`], and extension
+//! confirmation prompts are bridged through the same
+//! `ExtensionManager::set_ui_sender`/`respond_ui` pair `rpc` uses, just
+//! surfaced here as ACP's `session/request_permission`.
+//!
+//! Scope is the subset an editor needs to drive one turn-based session:
+//! `initialize`, `session/new`, `session/prompt`, `session/cancel`, and
+//! permission bridging. Anything else gets a JSON-RPC "method not found"
+//! error rather than being silently swallowed.
+
+#![allow(clippy::too_many_lines)]
+
+use crate::agent::{AbortHandle, AgentEvent, AgentSession};
+use crate::agent_cx::AgentCx;
+use crate::error::{Error, Result};
+use crate::extensions::{ExtensionManager, ExtensionUiRequest, ExtensionUiResponse};
+use crate::model::{AssistantMessageEvent, ContentBlock};
+use crate::rpc::{RpcOptions, try_send_line_with_backpressure};
+use crate::tools::ToolOutput;
+use asupersync::channel::{mpsc, oneshot};
+use asupersync::runtime::RuntimeHandle;
+use asupersync::sync::{Mutex, OwnedMutexGuard};
+use asupersync::time::{timeout, wall_now};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+
+/// Version of the ACP wire protocol implemented by [`run`].
+///
+/// Reported once in response to `initialize`.
+pub const ACP_PROTOCOL_VERSION: u64 = 1;
+
+/// Default budget for a `session/request_permission` round trip before it
+/// auto-resolves as cancelled, for extension UI requests that don't specify
+/// their own timeout.
+const DEFAULT_PERMISSION_TIMEOUT_MS: u64 = 60_000;
+
+type PendingPermissions = Arc<StdMutex<HashMap<u64, oneshot::Sender<Value>>>>;
+
+/// Serve the ACP protocol over stdio.
+///
+/// Mirrors [`crate::rpc::run_stdio`]'s OS-thread bridging: blocking reads on
+/// stdin feed an async channel, and a dedicated writer thread drains
+/// outgoing lines to stdout.
+pub async fn run_stdio(session: AgentSession, options: RpcOptions) -> Result<()> {
+    let (in_tx, in_rx) = mpsc::channel::<String>(1024);
+    let (out_tx, out_rx) = std::sync::mpsc::channel::<String>();
+
+    std::thread::spawn(move || {
+        let stdin = io::stdin();
+        let mut reader = io::BufReader::new(stdin.lock());
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let line_to_send = std::mem::take(&mut line);
+                    if !try_send_line_with_backpressure(&in_tx, line_to_send) {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+
+    std::thread::spawn(move || {
+        let stdout = io::stdout();
+        let mut writer = io::BufWriter::new(stdout.lock());
+        for line in out_rx {
+            if writer.write_all(line.as_bytes()).is_err() {
+                break;
+            }
+            if writer.write_all(b"\n").is_err() {
+                break;
+            }
+            if writer.flush().is_err() {
+                break;
+            }
+        }
+    });
+
+    run(session, options, in_rx, out_tx).await
+}
+
+async fn run(
+    mut session: AgentSession,
+    options: RpcOptions,
+    in_rx: mpsc::Receiver<String>,
+    out_tx: std::sync::mpsc::Sender<String>,
+) -> Result<()> {
+    let cx = AgentCx::for_request();
+    session.agent.set_queue_modes(
+        options.config.steering_queue_mode(),
+        options.config.follow_up_queue_mode(),
+    );
+
+    let extension_manager = session
+        .extensions
+        .as_ref()
+        .map(crate::extensions::ExtensionRegion::manager)
+        .cloned();
+    let session = Arc::new(Mutex::new(session));
+
+    let pending_permissions: PendingPermissions = Arc::new(StdMutex::new(HashMap::new()));
+    let next_permission_id = Arc::new(AtomicU64::new(1));
+
+    if let Some(manager) = extension_manager {
+        let (ui_tx, ui_rx) = mpsc::channel::<ExtensionUiRequest>(64);
+        manager.set_ui_sender(ui_tx);
+
+        let out_tx_ui = out_tx.clone();
+        let pending_ui = Arc::clone(&pending_permissions);
+        let next_id_ui = Arc::clone(&next_permission_id);
+        let runtime_handle_ui = options.runtime_handle.clone();
+        options.runtime_handle.spawn(async move {
+            let cx = AgentCx::for_request();
+            while let Ok(request) = ui_rx.recv(&cx).await {
+                if request.expects_response() {
+                    emit_permission_request(
+                        &runtime_handle_ui,
+                        manager.clone(),
+                        &out_tx_ui,
+                        &pending_ui,
+                        &next_id_ui,
+                        &request,
+                    );
+                } else {
+                    let _ = out_tx_ui.send(acp_notification(
+                        "session/update",
+                        &json!({
+                            "update": {
+                                "sessionUpdate": "extension_notification",
+                                "method": request.method,
+                                "payload": request.payload,
+                                "extensionId": request.extension_id,
+                            },
+                        }),
+                    ));
+                }
+            }
+        });
+    }
+
+    let acp_session_id: Arc<StdMutex<Option<String>>> = Arc::new(StdMutex::new(None));
+    let abort_handle_slot: Arc<Mutex<Option<AbortHandle>>> = Arc::new(Mutex::new(None));
+    // `abort_handle_slot` holds at most one live handle, so only one turn may
+    // run at a time -- otherwise a second `session/prompt` could overwrite
+    // (or clear) the first turn's handle out from under `session/cancel`.
+    let is_streaming = Arc::new(AtomicBool::new(false));
+
+    while let Ok(line) = in_rx.recv(&cx).await {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let parsed: Value = match serde_json::from_str(&line) {
+            Ok(v) => v,
+            Err(err) => {
+                let _ = out_tx.send(jsonrpc_error(
+                    &Value::Null,
+                    -32700,
+                    format!("Parse error: {err}"),
+                ));
+                continue;
+            }
+        };
+
+        if parsed.get("method").is_none() {
+            // Not a request or notification from the client -- it's a response to
+            // one of our own outgoing requests (`session/request_permission`).
+            if let Some(reply_id) = parsed.get("id").and_then(Value::as_u64) {
+                let sender = pending_permissions.lock().unwrap().remove(&reply_id);
+                if let Some(sender) = sender {
+                    let result = parsed.get("result").cloned().unwrap_or(Value::Null);
+                    let _ = sender.send(&cx, result);
+                }
+            }
+            continue;
+        }
+
+        let method = parsed
+            .get("method")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let id = parsed.get("id").cloned();
+        let params = parsed.get("params").cloned().unwrap_or(Value::Null);
+
+        match method.as_str() {
+            "initialize" => {
+                if let Some(id) = id {
+                    let _ = out_tx.send(jsonrpc_result(
+                        &id,
+                        &json!({
+                            "protocolVersion": ACP_PROTOCOL_VERSION,
+                            "agentCapabilities": {
+                                "loadSession": false,
+                                "promptCapabilities": {
+                                    "image": false,
+                                    "audio": false,
+                                    "embeddedContext": false,
+                                },
+                            },
+                            "authMethods": [],
+                        }),
+                    ));
+                }
+            }
+            "session/new" => {
+                let new_id = uuid::Uuid::new_v4().to_string();
+                *acp_session_id.lock().unwrap() = Some(new_id.clone());
+                if let Some(id) = id {
+                    let _ = out_tx.send(jsonrpc_result(&id, &json!({ "sessionId": new_id })));
+                }
+            }
+            "session/prompt" => {
+                let Some(id) = id else {
+                    // ACP requires a response, so a fire-and-forget prompt is malformed.
+                    continue;
+                };
+                let Some(requested_session_id) = params.get("sessionId").and_then(Value::as_str)
+                else {
+                    let _ = out_tx.send(jsonrpc_error(&id, -32602, "Missing sessionId"));
+                    continue;
+                };
+                if acp_session_id.lock().unwrap().as_deref() != Some(requested_session_id) {
+                    let _ = out_tx.send(jsonrpc_error(
+                        &id,
+                        -32602,
+                        format!("Unknown sessionId {requested_session_id:?}"),
+                    ));
+                    continue;
+                }
+
+                let text = extract_prompt_text(&params);
+                if text.is_empty() {
+                    let _ = out_tx.send(jsonrpc_error(
+                        &id,
+                        -32602,
+                        "Prompt contained no text content blocks",
+                    ));
+                    continue;
+                }
+
+                if is_streaming.swap(true, Ordering::SeqCst) {
+                    let _ = out_tx.send(jsonrpc_error(
+                        &id,
+                        -32000,
+                        "A turn is already in progress for this session",
+                    ));
+                    continue;
+                }
+
+                let session_id = requested_session_id.to_string();
+                let session = Arc::clone(&session);
+                let abort_handle_slot = Arc::clone(&abort_handle_slot);
+                let is_streaming = Arc::clone(&is_streaming);
+                let out_tx = out_tx.clone();
+                let cx_task = cx.clone();
+
+                options.runtime_handle.spawn(async move {
+                    let (abort_handle, abort_signal) = AbortHandle::new();
+                    if let Ok(mut guard) = abort_handle_slot.lock(&cx_task).await {
+                        *guard = Some(abort_handle);
+                    }
+
+                    let result = {
+                        let mut guard =
+                            match OwnedMutexGuard::lock(Arc::clone(&session), &cx_task).await {
+                                Ok(guard) => guard,
+                                Err(err) => {
+                                    let _ = out_tx.send(jsonrpc_error(
+                                        &id,
+                                        -32000,
+                                        format!("session lock failed: {err}"),
+                                    ));
+                                    if let Ok(mut guard) = abort_handle_slot.lock(&cx_task).await {
+                                        *guard = None;
+                                    }
+                                    is_streaming.store(false, Ordering::SeqCst);
+                                    return;
+                                }
+                            };
+                        let out_tx_events = out_tx.clone();
+                        let event_handler = move |event: AgentEvent| {
+                            if let Some(update) = map_agent_event(&event) {
+                                let _ = out_tx_events.send(acp_notification(
+                                    "session/update",
+                                    &json!({ "sessionId": session_id, "update": update }),
+                                ));
+                            }
+                        };
+                        guard
+                            .run_text_with_abort(text, Some(abort_signal), event_handler)
+                            .await
+                    };
+
+                    if let Ok(mut guard) = abort_handle_slot.lock(&cx_task).await {
+                        *guard = None;
+                    }
+                    is_streaming.store(false, Ordering::SeqCst);
+
+                    match result {
+                        Ok(_) => {
+                            let _ = out_tx
+                                .send(jsonrpc_result(&id, &json!({ "stopReason": "end_turn" })));
+                        }
+                        Err(err) => {
+                            let _ = out_tx.send(jsonrpc_error(&id, -32000, err.to_string()));
+                        }
+                    }
+                });
+            }
+            "session/cancel" => {
+                let handle = abort_handle_slot
+                    .lock(&cx)
+                    .await
+                    .map_err(|err| Error::session(format!("abort lock failed: {err}")))?
+                    .clone();
+                if let Some(handle) = handle {
+                    handle.abort();
+                }
+                if let Some(id) = id {
+                    let _ = out_tx.send(jsonrpc_result(&id, &Value::Null));
+                }
+            }
+            _ => {
+                if let Some(id) = id {
+                    let _ = out_tx.send(jsonrpc_error(
+                        &id,
+                        -32601,
+                        format!("Method not found: {method}"),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Forward an extension UI request that expects a reply to the client as a
+/// `session/request_permission` request, and resolve the extension's
+/// pending confirmation once the client answers (or the budget expires).
+fn emit_permission_request(
+    runtime_handle: &RuntimeHandle,
+    manager: ExtensionManager,
+    out_tx: &std::sync::mpsc::Sender<String>,
+    pending: &PendingPermissions,
+    next_id: &Arc<AtomicU64>,
+    request: &ExtensionUiRequest,
+) {
+    let outgoing_id = next_id.fetch_add(1, Ordering::SeqCst);
+    let (tx, rx) = oneshot::channel::<Value>();
+    pending.lock().unwrap().insert(outgoing_id, tx);
+
+    let _ = out_tx.send(jsonrpc_request(
+        outgoing_id,
+        "session/request_permission",
+        &json!({
+            "toolCall": {
+                "toolCallId": request.id,
+                "title": request.method,
+            },
+            "extensionId": request.extension_id,
+            "payload": request.payload,
+        }),
+    ));
+
+    let budget_ms = request
+        .effective_timeout_ms()
+        .unwrap_or(DEFAULT_PERMISSION_TIMEOUT_MS);
+    let request_id = request.id.clone();
+    let pending_timeout = Arc::clone(pending);
+
+    runtime_handle.spawn(async move {
+        let cx = AgentCx::for_request();
+        let budget = Duration::from_millis(budget_ms);
+
+        let response = match timeout(wall_now(), budget, rx.recv(&cx)).await {
+            Ok(Ok(value)) => Some(value),
+            Ok(Err(_)) | Err(_) => None,
+        };
+
+        pending_timeout.lock().unwrap().remove(&outgoing_id);
+
+        let ui_response = response
+            .map(|value| parse_permission_result(&request_id, &value))
+            .unwrap_or(ExtensionUiResponse {
+                id: request_id,
+                value: None,
+                cancelled: true,
+            });
+        let _ = manager.respond_ui(ui_response);
+    });
+}
+
+/// Interpret the client's `session/request_permission` result as an
+/// [`ExtensionUiResponse`]. `{"outcome": "cancelled"}` maps to a cancelled
+/// response; anything else is treated as the confirmation value, with
+/// `selected` (if present) taking precedence for `select`-style prompts.
+fn parse_permission_result(request_id: &str, result: &Value) -> ExtensionUiResponse {
+    let cancelled = result
+        .get("outcome")
+        .and_then(Value::as_str)
+        .is_some_and(|outcome| outcome == "cancelled");
+
+    let value = result
+        .get("selected")
+        .or_else(|| result.get("value"))
+        .cloned();
+
+    ExtensionUiResponse {
+        id: request_id.to_string(),
+        value,
+        cancelled,
+    }
+}
+
+/// Concatenate the text content blocks of an ACP `session/prompt` request
+/// into a single string. Non-text blocks (images, embedded resources) are
+/// outside this adapter's scope and are skipped.
+fn extract_prompt_text(params: &Value) -> String {
+    params
+        .get("prompt")
+        .and_then(Value::as_array)
+        .map(|blocks| {
+            blocks
+                .iter()
+                .filter_map(|block| {
+                    if block.get("type").and_then(Value::as_str) == Some("text") {
+                        block.get("text").and_then(Value::as_str)
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("")
+        })
+        .unwrap_or_default()
+}
+
+/// Map an agent event to an ACP `session/update` payload, if it has an ACP
+/// equivalent. Lifecycle events with no ACP analogue (turn/message
+/// start-end, compaction, retries, ...) are dropped.
+fn map_agent_event(event: &AgentEvent) -> Option<Value> {
+    match event {
+        AgentEvent::MessageUpdate {
+            assistant_message_event,
+            ..
+        } => match assistant_message_event {
+            AssistantMessageEvent::TextDelta { delta, .. } => Some(json!({
+                "sessionUpdate": "agent_message_chunk",
+                "content": { "type": "text", "text": delta },
+            })),
+            AssistantMessageEvent::ThinkingDelta { delta, .. } => Some(json!({
+                "sessionUpdate": "agent_thought_chunk",
+                "content": { "type": "text", "text": delta },
+            })),
+            _ => None,
+        },
+        AgentEvent::ToolExecutionStart {
+            tool_call_id,
+            tool_name,
+            args,
+        } => Some(json!({
+            "sessionUpdate": "tool_call",
+            "toolCallId": tool_call_id,
+            "title": tool_name,
+            "status": "in_progress",
+            "rawInput": args,
+        })),
+        AgentEvent::ToolExecutionUpdate {
+            tool_call_id,
+            tool_name,
+            partial_result,
+            ..
+        } => Some(json!({
+            "sessionUpdate": "tool_call_update",
+            "toolCallId": tool_call_id,
+            "title": tool_name,
+            "status": "in_progress",
+            "content": tool_output_to_acp_content(partial_result),
+        })),
+        AgentEvent::ToolExecutionEnd {
+            tool_call_id,
+            tool_name,
+            result,
+            is_error,
+        } => Some(json!({
+            "sessionUpdate": "tool_call_update",
+            "toolCallId": tool_call_id,
+            "title": tool_name,
+            "status": if *is_error { "failed" } else { "completed" },
+            "content": tool_output_to_acp_content(result),
+        })),
+        _ => None,
+    }
+}
+
+fn tool_output_to_acp_content(output: &ToolOutput) -> Vec<Value> {
+    output
+        .content
+        .iter()
+        .filter_map(|block| match block {
+            ContentBlock::Text(text) => Some(json!({ "type": "text", "text": text.text })),
+            _ => None,
+        })
+        .collect()
+}
+
+fn jsonrpc_result(id: &Value, result: &Value) -> String {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result }).to_string()
+}
+
+fn jsonrpc_error(id: &Value, code: i64, message: impl Into<String>) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message.into() },
+    })
+    .to_string()
+}
+
+fn jsonrpc_request(id: u64, method: &str, params: &Value) -> String {
+    json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }).to_string()
+}
+
+fn acp_notification(method: &str, params: &Value) -> String {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_prompt_text_concatenates_text_blocks_only() {
+        let params = json!({
+            "sessionId": "s1",
+            "prompt": [
+                { "type": "text", "text": "Hello, " },
+                { "type": "image", "data": "..." },
+                { "type": "text", "text": "world!" },
+            ],
+        });
+        assert_eq!(extract_prompt_text(&params), "Hello, world!");
+    }
+
+    #[test]
+    fn extract_prompt_text_empty_when_no_text_blocks() {
+        let params = json!({ "sessionId": "s1", "prompt": [] });
+        assert_eq!(extract_prompt_text(&params), "");
+    }
+
+    #[test]
+    fn parse_permission_result_prefers_selected_over_value() {
+        let result = json!({ "selected": "allow_once", "value": "ignored" });
+        let resp = parse_permission_result("req-1", &result);
+        assert_eq!(resp.id, "req-1");
+        assert_eq!(resp.value, Some(json!("allow_once")));
+        assert!(!resp.cancelled);
+    }
+
+    #[test]
+    fn parse_permission_result_detects_cancelled_outcome() {
+        let result = json!({ "outcome": "cancelled" });
+        let resp = parse_permission_result("req-2", &result);
+        assert!(resp.cancelled);
+        assert_eq!(resp.value, None);
+    }
+
+    #[test]
+    fn map_agent_event_maps_text_delta_to_agent_message_chunk() {
+        use crate::model::AssistantMessage;
+
+        let partial = Arc::new(AssistantMessage::default());
+        let event = AgentEvent::MessageUpdate {
+            message: crate::model::Message::Assistant(Arc::clone(&partial)),
+            assistant_message_event: AssistantMessageEvent::TextDelta {
+                content_index: 0,
+                delta: "hi".to_string(),
+                partial,
+            },
+        };
+        let update = map_agent_event(&event).expect("text delta maps to an update");
+        assert_eq!(update["sessionUpdate"], "agent_message_chunk");
+        assert_eq!(update["content"]["text"], "hi");
+    }
+
+    #[test]
+    fn map_agent_event_drops_lifecycle_events_without_acp_analogue() {
+        let event = AgentEvent::AgentStart {
+            session_id: Arc::from(""),
+        };
+        assert!(map_agent_event(&event).is_none());
+    }
+
+    #[test]
+    fn jsonrpc_helpers_produce_expected_envelopes() {
+        let result = jsonrpc_result(&json!(1), &json!({ "ok": true }));
+        let parsed: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed["jsonrpc"], "2.0");
+        assert_eq!(parsed["id"], 1);
+        assert_eq!(parsed["result"]["ok"], true);
+
+        let error = jsonrpc_error(&json!(2), -32601, "nope");
+        let parsed: Value = serde_json::from_str(&error).unwrap();
+        assert_eq!(parsed["error"]["code"], -32601);
+        assert_eq!(parsed["error"]["message"], "nope");
+
+        let notif = acp_notification("session/update", &json!({ "a": 1 }));
+        let parsed: Value = serde_json::from_str(&notif).unwrap();
+        assert!(parsed.get("id").is_none());
+        assert_eq!(parsed["method"], "session/update");
+    }
+}
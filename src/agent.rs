@@ -475,6 +475,17 @@ impl Agent {
         self.cached_tool_defs = None; // Invalidate cache when tools change
     }
 
+    /// Replace the tool registry wholesale (used for runtime mode/profile switching).
+    pub fn set_tools(&mut self, tools: ToolRegistry) {
+        self.tools = tools;
+        self.cached_tool_defs = None; // Invalidate cache when tools change
+    }
+
+    /// Replace the system prompt (used for runtime mode/profile switching).
+    pub fn set_system_prompt(&mut self, system_prompt: Option<String>) {
+        self.config.system_prompt = system_prompt;
+    }
+
     /// Queue a steering message (delivered after tool completion).
     pub fn queue_steering(&mut self, message: Message) -> u64 {
         self.message_queue.push_steering(message)
@@ -2054,11 +2065,53 @@ pub struct AgentSession {
     /// Extension lifecycle region — ensures the JS runtime thread is shut
     /// down when the session ends.
     pub extensions: Option<ExtensionRegion>,
+    /// Set when extension loading was skipped because the runtime failed to
+    /// start; the session otherwise continues normally with built-in tools
+    /// only. See [`ExtensionDegradationNotice`].
+    pub extension_degradation: Option<ExtensionDegradationNotice>,
     extensions_is_streaming: Arc<AtomicBool>,
     compaction_settings: ResolvedCompactionSettings,
     compaction_worker: CompactionWorkerState,
     model_registry: Option<ModelRegistry>,
     auth_storage: Option<AuthStorage>,
+    #[cfg(feature = "otel")]
+    otel: Option<Arc<crate::otel::OtelExporter>>,
+}
+
+/// Describes why the extension runtime was skipped for this session (starting
+/// the runtime returned an error) and which capabilities are unavailable as a
+/// result. Only runtime start-up errors produce a notice: errors from
+/// resolving extension entries (including WASM entries in a build without the
+/// `wasm-host` feature) or from loading individual extensions still fail
+/// session startup.
+///
+/// Surfaced by the interactive UI as a persistent banner (dismissible via
+/// [`Self::dismiss`]) and recorded as a `extension_degraded` custom session
+/// entry so it shows up when the session is replayed or exported.
+#[derive(Debug, Clone)]
+pub struct ExtensionDegradationNotice {
+    pub reason: String,
+    pub degraded_capabilities: Vec<String>,
+    dismissed: bool,
+}
+
+impl ExtensionDegradationNotice {
+    const fn new(reason: String, degraded_capabilities: Vec<String>) -> Self {
+        Self {
+            reason,
+            degraded_capabilities,
+            dismissed: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_dismissed(&self) -> bool {
+        self.dismissed
+    }
+
+    pub const fn dismiss(&mut self) {
+        self.dismissed = true;
+    }
 }
 
 #[derive(Debug, Default)]
@@ -2271,7 +2324,7 @@ mod message_queue_tests {
 mod extensions_integration_tests {
     use super::*;
 
-    use crate::session::Session;
+    use crate::session::{Session, SessionEntry};
     use asupersync::runtime::RuntimeBuilder;
     use async_trait::async_trait;
     use futures::Stream;
@@ -2561,6 +2614,65 @@ mod extensions_integration_tests {
         });
     }
 
+    #[test]
+    fn degrade_extensions_records_notice_and_session_entry() {
+        let runtime = RuntimeBuilder::current_thread()
+            .build()
+            .expect("runtime build");
+
+        runtime.block_on(async {
+            let provider = Arc::new(NoopProvider);
+            let tools = ToolRegistry::new(&[], Path::new("."), None);
+            let agent = Agent::new(provider, tools, AgentConfig::default());
+            let session = Arc::new(Mutex::new(Session::in_memory()));
+            let mut agent_session =
+                AgentSession::new(agent, session, false, ResolvedCompactionSettings::default());
+
+            assert!(agent_session.extension_degradation.is_none());
+
+            let err = Error::extension("boom: quickjs runtime unavailable");
+            agent_session
+                .degrade_extensions(&err, vec!["ext.mjs".to_string()])
+                .await;
+
+            let reason = {
+                let notice = agent_session
+                    .extension_degradation
+                    .as_ref()
+                    .expect("degradation notice recorded");
+                assert!(notice.reason.contains("boom"));
+                assert_eq!(notice.degraded_capabilities, vec!["ext.mjs".to_string()]);
+                assert!(!notice.is_dismissed());
+                notice.reason.clone()
+            };
+
+            agent_session.dismiss_extension_degradation();
+            assert!(
+                agent_session
+                    .extension_degradation
+                    .as_ref()
+                    .expect("notice still present after dismiss")
+                    .is_dismissed()
+            );
+
+            let cx = crate::agent_cx::AgentCx::for_request();
+            let session = agent_session.session.lock(cx.cx()).await.expect("lock session");
+            let recorded = session
+                .entries_for_current_path()
+                .into_iter()
+                .find_map(|entry| match entry {
+                    SessionEntry::Custom(custom) if custom.custom_type == "extension_degraded" => {
+                        Some(custom)
+                    }
+                    _ => None,
+                })
+                .expect("extension_degraded session entry recorded");
+            let data = recorded.data.as_ref().expect("entry data present");
+            assert_eq!(data["reason"].as_str().unwrap(), reason);
+            assert_eq!(data["degradedCapabilities"][0].as_str().unwrap(), "ext.mjs");
+        });
+    }
+
     #[test]
     fn extension_send_message_persists_custom_message_entry_when_idle() {
         let runtime = RuntimeBuilder::current_thread()
@@ -4459,6 +4571,137 @@ impl AgentSession {
         Ok(ExtensionRuntimeHandle::NativeRust(runtime))
     }
 
+    /// Boot (or adopt a pre-warmed) extension runtime. Split out from
+    /// [`Self::enable_extensions_with_policy`] so runtime-startup failures
+    /// can be caught in one place and turned into a degradation notice
+    /// instead of a fatal session-startup error.
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+    async fn boot_extension_runtime(
+        pre_warmed: Option<PreWarmedExtensionRuntime>,
+        wants_js_runtime: bool,
+        cwd: &std::path::Path,
+        enabled_tools: &[&str],
+        config: Option<&crate::config::Config>,
+        resolved_policy: ExtensionPolicy,
+        runtime_repair_mode: RepairMode,
+        memory_limit_bytes: usize,
+    ) -> Result<(ExtensionManager, Arc<ToolRegistry>)> {
+        let (manager, tools) = if let Some(pre) = pre_warmed {
+            let manager = pre.manager;
+            let tools = pre.tools;
+            let runtime = match pre.runtime {
+                ExtensionRuntimeHandle::NativeRust(runtime) => {
+                    if wants_js_runtime {
+                        tracing::warn!(
+                            event = "pi.extension_runtime.prewarm.mismatch",
+                            expected = "quickjs",
+                            got = "native-rust",
+                            "Pre-warmed runtime mismatched requested JS mode; creating quickjs runtime"
+                        );
+                        Self::start_js_extension_runtime(
+                            "agent_enable_extensions_prewarm_mismatch",
+                            cwd,
+                            Arc::clone(&tools),
+                            manager.clone(),
+                            resolved_policy.clone(),
+                            runtime_repair_mode,
+                            memory_limit_bytes,
+                        )
+                        .await?
+                    } else {
+                        tracing::info!(
+                            event = "pi.extension_runtime.engine_decision",
+                            stage = "agent_enable_extensions_prewarmed",
+                            requested = "native-rust",
+                            selected = "native-rust",
+                            fallback = false,
+                            "Using pre-warmed extension runtime"
+                        );
+                        ExtensionRuntimeHandle::NativeRust(runtime)
+                    }
+                }
+                ExtensionRuntimeHandle::Js(runtime) => {
+                    if wants_js_runtime {
+                        tracing::info!(
+                            event = "pi.extension_runtime.engine_decision",
+                            stage = "agent_enable_extensions_prewarmed",
+                            requested = "quickjs",
+                            selected = "quickjs",
+                            fallback = false,
+                            "Using pre-warmed extension runtime"
+                        );
+                        ExtensionRuntimeHandle::Js(runtime)
+                    } else {
+                        tracing::warn!(
+                            event = "pi.extension_runtime.prewarm.mismatch",
+                            expected = "native-rust",
+                            got = "quickjs",
+                            "Pre-warmed runtime mismatched requested native mode; creating native-rust runtime"
+                        );
+                        Self::start_native_extension_runtime(
+                            "agent_enable_extensions_prewarm_mismatch",
+                            cwd,
+                            Arc::clone(&tools),
+                            manager.clone(),
+                            resolved_policy.clone(),
+                            runtime_repair_mode,
+                            memory_limit_bytes,
+                        )
+                        .await?
+                    }
+                }
+            };
+            manager.set_runtime(runtime);
+            (manager, tools)
+        } else {
+            let manager = ExtensionManager::new();
+            manager.set_cwd(cwd.display().to_string());
+            let tools = Arc::new(ToolRegistry::new(enabled_tools, cwd, config));
+
+            if let Some(cfg) = config {
+                let resolved_risk = cfg.resolve_extension_risk_with_metadata();
+                tracing::info!(
+                    event = "pi.extension_runtime_risk.config",
+                    source = resolved_risk.source,
+                    enabled = resolved_risk.settings.enabled,
+                    alpha = resolved_risk.settings.alpha,
+                    window_size = resolved_risk.settings.window_size,
+                    ledger_limit = resolved_risk.settings.ledger_limit,
+                    fail_closed = resolved_risk.settings.fail_closed,
+                    "Resolved extension runtime risk settings"
+                );
+                manager.set_runtime_risk_config(resolved_risk.settings);
+            }
+
+            let runtime = if wants_js_runtime {
+                Self::start_js_extension_runtime(
+                    "agent_enable_extensions_boot",
+                    cwd,
+                    Arc::clone(&tools),
+                    manager.clone(),
+                    resolved_policy,
+                    runtime_repair_mode,
+                    memory_limit_bytes,
+                )
+                .await?
+            } else {
+                Self::start_native_extension_runtime(
+                    "agent_enable_extensions_boot",
+                    cwd,
+                    Arc::clone(&tools),
+                    manager.clone(),
+                    resolved_policy,
+                    runtime_repair_mode,
+                    memory_limit_bytes,
+                )
+                .await?
+            };
+            manager.set_runtime(runtime);
+            (manager, tools)
+        };
+        Ok((manager, tools))
+    }
+
     pub fn new(
         agent: Agent,
         session: Arc<Mutex<Session>>,
@@ -4470,11 +4713,14 @@ impl AgentSession {
             session,
             save_enabled,
             extensions: None,
+            extension_degradation: None,
             extensions_is_streaming: Arc::new(AtomicBool::new(false)),
             compaction_settings,
             compaction_worker: CompactionWorkerState::new(CompactionQuota::default()),
             model_registry: None,
             auth_storage: None,
+            #[cfg(feature = "otel")]
+            otel: None,
         }
     }
 
@@ -4490,6 +4736,16 @@ impl AgentSession {
         self
     }
 
+    /// Attach an OTLP exporter so turns started through
+    /// [`Self::run_text_with_abort`] are recorded as spans. Only compiled with
+    /// the `otel` feature.
+    #[cfg(feature = "otel")]
+    #[must_use]
+    pub fn with_otel_exporter(mut self, exporter: Arc<crate::otel::OtelExporter>) -> Self {
+        self.otel = Some(exporter);
+        self
+    }
+
     pub fn set_model_registry(&mut self, registry: ModelRegistry) {
         self.model_registry = Some(registry);
     }
@@ -4498,6 +4754,44 @@ impl AgentSession {
         self.auth_storage = Some(auth);
     }
 
+    /// Dismiss the extension-degradation banner, if one is active.
+    /// The underlying session entry is left in place as a historical record.
+    pub const fn dismiss_extension_degradation(&mut self) {
+        if let Some(notice) = self.extension_degradation.as_mut() {
+            notice.dismiss();
+        }
+    }
+
+    /// Record that extension loading was skipped and the session is
+    /// continuing with built-in tools only, instead of failing startup.
+    /// Logs a warning event and appends an `extension_degraded` custom
+    /// session entry describing the degraded capabilities and why.
+    async fn degrade_extensions(&mut self, err: &Error, degraded_capabilities: Vec<String>) {
+        let reason = err.to_string();
+        tracing::warn!(
+            event = "pi.extension_runtime.degraded",
+            error = %reason,
+            degraded = ?degraded_capabilities,
+            "Extension runtime failed to start; continuing session with built-in tools only"
+        );
+
+        let cx = crate::agent_cx::AgentCx::for_request();
+        if let Ok(mut session) = self.session.lock(cx.cx()).await {
+            session.append_custom_entry(
+                "extension_degraded".to_string(),
+                Some(serde_json::json!({
+                    "reason": reason,
+                    "degradedCapabilities": degraded_capabilities,
+                })),
+            );
+        }
+
+        self.extension_degradation = Some(ExtensionDegradationNotice::new(
+            reason,
+            degraded_capabilities,
+        ));
+    }
+
     pub async fn set_provider_model(&mut self, provider_id: &str, model_id: &str) -> Result<()> {
         {
             let cx = crate::agent_cx::AgentCx::for_request();
@@ -4767,11 +5061,13 @@ impl AgentSession {
             None,
             None,
             None,
+            None,
         )
         .await
     }
 
     #[allow(clippy::too_many_lines, clippy::too_many_arguments)]
+    #[cfg_attr(not(feature = "wasm-host"), allow(unused_variables))]
     pub async fn enable_extensions_with_policy(
         &mut self,
         enabled_tools: &[&str],
@@ -4781,6 +5077,7 @@ impl AgentSession {
         policy: Option<ExtensionPolicy>,
         repair_policy: Option<RepairPolicyMode>,
         pre_warmed: Option<PreWarmedExtensionRuntime>,
+        workspace_roots: Option<&crate::workspace::WorkspaceRoots>,
     ) -> Result<()> {
         let mut js_specs: Vec<JsExtensionLoadSpec> = Vec::new();
         let mut native_specs: Vec<NativeRustExtensionLoadSpec> = Vec::new();
@@ -4814,120 +5111,34 @@ impl AgentSession {
         let wants_js_runtime = !js_specs.is_empty();
 
         // Either use the pre-warmed extension runtime (booted concurrently with startup)
-        // or create a fresh runtime inline.
-        #[allow(unused_variables)]
-        let (manager, tools) = if let Some(pre) = pre_warmed {
-            let manager = pre.manager;
-            let tools = pre.tools;
-            let runtime = match pre.runtime {
-                ExtensionRuntimeHandle::NativeRust(runtime) => {
-                    if wants_js_runtime {
-                        tracing::warn!(
-                            event = "pi.extension_runtime.prewarm.mismatch",
-                            expected = "quickjs",
-                            got = "native-rust",
-                            "Pre-warmed runtime mismatched requested JS mode; creating quickjs runtime"
-                        );
-                        Self::start_js_extension_runtime(
-                            "agent_enable_extensions_prewarm_mismatch",
-                            cwd,
-                            Arc::clone(&tools),
-                            manager.clone(),
-                            resolved_policy.clone(),
-                            runtime_repair_mode,
-                            memory_limit_bytes,
-                        )
-                        .await?
-                    } else {
-                        tracing::info!(
-                            event = "pi.extension_runtime.engine_decision",
-                            stage = "agent_enable_extensions_prewarmed",
-                            requested = "native-rust",
-                            selected = "native-rust",
-                            fallback = false,
-                            "Using pre-warmed extension runtime"
-                        );
-                        ExtensionRuntimeHandle::NativeRust(runtime)
-                    }
-                }
-                ExtensionRuntimeHandle::Js(runtime) => {
-                    if wants_js_runtime {
-                        tracing::info!(
-                            event = "pi.extension_runtime.engine_decision",
-                            stage = "agent_enable_extensions_prewarmed",
-                            requested = "quickjs",
-                            selected = "quickjs",
-                            fallback = false,
-                            "Using pre-warmed extension runtime"
-                        );
-                        ExtensionRuntimeHandle::Js(runtime)
-                    } else {
-                        tracing::warn!(
-                            event = "pi.extension_runtime.prewarm.mismatch",
-                            expected = "native-rust",
-                            got = "quickjs",
-                            "Pre-warmed runtime mismatched requested native mode; creating native-rust runtime"
-                        );
-                        Self::start_native_extension_runtime(
-                            "agent_enable_extensions_prewarm_mismatch",
-                            cwd,
-                            Arc::clone(&tools),
-                            manager.clone(),
-                            resolved_policy.clone(),
-                            runtime_repair_mode,
-                            memory_limit_bytes,
-                        )
-                        .await?
-                    }
+        // or create a fresh runtime inline. If starting the runtime returns an error,
+        // degrade instead of failing session startup: continue with built-in tools only.
+        // Errors from resolving entries above and from loading extensions below are
+        // still returned to the caller.
+        let (manager, tools) = match Self::boot_extension_runtime(
+            pre_warmed,
+            wants_js_runtime,
+            cwd,
+            enabled_tools,
+            config,
+            resolved_policy,
+            runtime_repair_mode,
+            memory_limit_bytes,
+        )
+        .await
+        {
+            Ok(pair) => pair,
+            Err(err) => {
+                let mut degraded_capabilities: Vec<String> = extension_entries
+                    .iter()
+                    .filter_map(|p| p.file_name().map(|n| n.to_string_lossy().into_owned()))
+                    .collect();
+                if degraded_capabilities.is_empty() {
+                    degraded_capabilities.push("extensions".to_string());
                 }
-            };
-            manager.set_runtime(runtime);
-            (manager, tools)
-        } else {
-            let manager = ExtensionManager::new();
-            manager.set_cwd(cwd.display().to_string());
-            let tools = Arc::new(ToolRegistry::new(enabled_tools, cwd, config));
-
-            if let Some(cfg) = config {
-                let resolved_risk = cfg.resolve_extension_risk_with_metadata();
-                tracing::info!(
-                    event = "pi.extension_runtime_risk.config",
-                    source = resolved_risk.source,
-                    enabled = resolved_risk.settings.enabled,
-                    alpha = resolved_risk.settings.alpha,
-                    window_size = resolved_risk.settings.window_size,
-                    ledger_limit = resolved_risk.settings.ledger_limit,
-                    fail_closed = resolved_risk.settings.fail_closed,
-                    "Resolved extension runtime risk settings"
-                );
-                manager.set_runtime_risk_config(resolved_risk.settings);
+                self.degrade_extensions(&err, degraded_capabilities).await;
+                return Ok(());
             }
-
-            let runtime = if wants_js_runtime {
-                Self::start_js_extension_runtime(
-                    "agent_enable_extensions_boot",
-                    cwd,
-                    Arc::clone(&tools),
-                    manager.clone(),
-                    resolved_policy,
-                    runtime_repair_mode,
-                    memory_limit_bytes,
-                )
-                .await?
-            } else {
-                Self::start_native_extension_runtime(
-                    "agent_enable_extensions_boot",
-                    cwd,
-                    Arc::clone(&tools),
-                    manager.clone(),
-                    resolved_policy,
-                    runtime_repair_mode,
-                    memory_limit_bytes,
-                )
-                .await?
-            };
-            manager.set_runtime(runtime);
-            (manager, tools)
         };
 
         // Session, host actions, and message fetchers are always set here
@@ -4987,7 +5198,10 @@ impl AgentSession {
 
         #[cfg(feature = "wasm-host")]
         if !wasm_specs.is_empty() {
-            let host = WasmExtensionHost::new(cwd, policy.unwrap_or_default())?;
+            let mut host = WasmExtensionHost::new(cwd, policy.unwrap_or_default())?;
+            if let Some(roots) = workspace_roots {
+                host = host.with_workspace(roots.clone());
+            }
             manager
                 .load_wasm_extensions(&host, wasm_specs, Arc::clone(&tools))
                 .await?;
@@ -5082,12 +5296,34 @@ impl AgentSession {
 
         self.dispatch_before_agent_start().await;
 
-        if images.is_empty() {
+        #[cfg(feature = "otel")]
+        let otel_span = self
+            .otel
+            .as_ref()
+            .map(|exporter| (exporter.clone(), crate::otel::SpanBuilder::start(
+                "agent.turn",
+                crate::otel::SpanKind::AgentTurn,
+                crate::otel::new_trace_id(),
+            )));
+
+        let result = if images.is_empty() {
             self.run_agent_with_text(text, abort, on_event).await
         } else {
             let content = Self::build_content_blocks_for_input(&text, &images);
             self.run_agent_with_content(content, abort, on_event).await
+        };
+
+        #[cfg(feature = "otel")]
+        if let Some((exporter, span)) = otel_span {
+            let status = if result.is_ok() { "ok" } else { "error" };
+            let span = span.attribute("status", status).finish();
+            exporter.record_span(span);
+            if let Err(err) = exporter.flush().await {
+                tracing::warn!("otel export failed (fail-open): {err}");
+            }
         }
+
+        result
     }
 
     pub async fn run_with_content(
@@ -90,10 +90,47 @@ pub fn normalize_cli(cli: &mut cli::Cli) {
     }
 }
 
+/// Default value of `Cli::tools`, kept in sync with the `#[arg(default_value = ...)]`
+/// on that field so we can tell an explicit `--tools` from the untouched default.
+const DEFAULT_TOOLS: &str = "read,bash,edit,write";
+
+/// Applies a named agent profile ("mode") selected with `--profile` on top of `cli`.
+///
+/// Explicit CLI flags always win over the profile; the profile only fills in
+/// fields left at their default (or unset).
+pub fn apply_agent_profile(cli: &mut cli::Cli, config: &Config) -> Result<()> {
+    let Some(name) = &cli.profile else {
+        return Ok(());
+    };
+    let Some(profile) = config.profiles.as_ref().and_then(|profiles| profiles.get(name)) else {
+        bail!("Unknown agent profile: {name}");
+    };
+
+    if cli.system_prompt.is_none() {
+        cli.system_prompt = profile.system_prompt.clone();
+    }
+    if cli.tools == DEFAULT_TOOLS {
+        if let Some(tools) = &profile.tools {
+            cli.tools = tools.clone();
+        }
+    }
+    if cli.model.is_none() {
+        cli.model = profile.model.clone();
+    }
+    if cli.extension_policy.is_none() {
+        cli.extension_policy = profile.extension_policy.clone();
+    }
+
+    Ok(())
+}
+
 pub fn validate_rpc_args(cli: &cli::Cli) -> Result<()> {
     if cli.mode.as_deref() == Some("rpc") && !cli.file_args().is_empty() {
         bail!("Error: @file arguments are not supported in RPC mode");
     }
+    if cli.mode.as_deref() == Some("acp") && !cli.file_args().is_empty() {
+        bail!("Error: @file arguments are not supported in ACP mode");
+    }
     Ok(())
 }
 
@@ -319,7 +356,7 @@ fn load_project_context_files(cwd: &Path, global_dir: &Path) -> Vec<ContextFile>
 }
 
 fn load_context_file_from_dir(dir: &Path) -> Option<ContextFile> {
-    let candidates = ["AGENTS.md", "CLAUDE.md"];
+    let candidates = ["PI.md", "AGENTS.md", "CLAUDE.md"];
     for filename in candidates {
         let path = dir.join(filename);
         if path.exists() {
@@ -1193,6 +1230,62 @@ mod tests {
         assert_eq!(cli.args, vec!["existing-message".to_string()]);
     }
 
+    #[test]
+    fn apply_agent_profile_fills_unset_model_and_extension_policy() {
+        let mut cli = cli::Cli::parse_from(["pi", "--profile", "reviewer"]);
+        let mut profiles = std::collections::HashMap::new();
+        profiles.insert(
+            "reviewer".to_string(),
+            crate::config::AgentProfile {
+                system_prompt: Some("Review code carefully.".to_string()),
+                tools: None,
+                model: Some("anthropic/claude-opus".to_string()),
+                extension_policy: Some("safe".to_string()),
+            },
+        );
+        let config = Config {
+            profiles: Some(profiles),
+            ..Config::default()
+        };
+
+        apply_agent_profile(&mut cli, &config).expect("known profile applies");
+
+        assert_eq!(cli.model.as_deref(), Some("anthropic/claude-opus"));
+        assert_eq!(cli.extension_policy.as_deref(), Some("safe"));
+    }
+
+    #[test]
+    fn apply_agent_profile_does_not_override_explicit_cli_flags() {
+        let mut cli = cli::Cli::parse_from([
+            "pi",
+            "--profile",
+            "reviewer",
+            "--model",
+            "openai/gpt-4o",
+            "--extension-policy",
+            "permissive",
+        ]);
+        let mut profiles = std::collections::HashMap::new();
+        profiles.insert(
+            "reviewer".to_string(),
+            crate::config::AgentProfile {
+                system_prompt: None,
+                tools: None,
+                model: Some("anthropic/claude-opus".to_string()),
+                extension_policy: Some("safe".to_string()),
+            },
+        );
+        let config = Config {
+            profiles: Some(profiles),
+            ..Config::default()
+        };
+
+        apply_agent_profile(&mut cli, &config).expect("known profile applies");
+
+        assert_eq!(cli.model.as_deref(), Some("openai/gpt-4o"));
+        assert_eq!(cli.extension_policy.as_deref(), Some("permissive"));
+    }
+
     #[test]
     fn normalize_cli_enables_no_session_for_print_and_lowercases_provider() {
         let mut cli = cli::Cli::parse_from(["pi", "--provider", "OpenAI", "--print", "hello"]);
@@ -0,0 +1,349 @@
+//! Non-interactive batch execution (`pi run --prompt-file ... --output ...`).
+//!
+//! Reads one prompt per line from a JSONL file, runs each through a fresh
+//! [`AgentSession`] built from the model/tool configuration resolved at
+//! startup, and writes one structured result record per prompt to an output
+//! JSONL file. Unlike the interactive/print/rpc/acp modes, prompts do not
+//! share conversation history with one another — each gets its own session.
+//! Progress is tracked in an optional checkpoint file so an interrupted run
+//! can be resumed without re-processing prompts that already completed.
+
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::agent::{Agent, AgentConfig, AgentEvent, AgentSession};
+use crate::compaction::ResolvedCompactionSettings;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::model::ContentBlock;
+use crate::provider::Provider;
+use crate::session::Session;
+use crate::tools::ToolRegistry;
+
+/// Parsed `pi run` invocation, threaded from the CLI down to [`run_batch`].
+#[derive(Debug, Clone)]
+pub struct BatchRequest {
+    pub prompt_file: PathBuf,
+    pub output: PathBuf,
+    pub concurrency: usize,
+    pub checkpoint: Option<PathBuf>,
+}
+
+/// One line of the `--prompt-file` input.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchPrompt {
+    /// Caller-supplied identifier, used in the output record and checkpoint.
+    /// Defaults to the 0-based line index when omitted.
+    #[serde(default)]
+    pub id: Option<String>,
+    /// The user message to send to the agent.
+    pub prompt: String,
+}
+
+/// One line of the `--output` JSONL file.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchResult {
+    pub id: String,
+    pub prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub final_text: Option<String>,
+    pub tool_trace: Vec<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<Value>,
+    pub duration_ms: u128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl BatchResult {
+    const fn is_error(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+/// Totals reported after a batch run completes.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BatchSummary {
+    pub total: usize,
+    pub skipped: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+/// Recipe for building an independent [`AgentSession`] for a single prompt.
+///
+/// Each prompt (and each concurrent worker) gets its own session built from
+/// this shared configuration, so prompts never see one another's history.
+#[derive(Clone)]
+pub struct BatchSessionFactory {
+    pub provider: Arc<dyn Provider>,
+    pub enabled_tools: Vec<String>,
+    pub cwd: PathBuf,
+    pub config: Arc<Config>,
+    pub agent_config: AgentConfig,
+    pub compaction_settings: ResolvedCompactionSettings,
+}
+
+impl BatchSessionFactory {
+    fn build_session(&self) -> AgentSession {
+        let tool_refs: Vec<&str> = self.enabled_tools.iter().map(String::as_str).collect();
+        let tools = ToolRegistry::new(&tool_refs, &self.cwd, Some(&self.config));
+        let agent = Agent::new(
+            Arc::clone(&self.provider),
+            tools,
+            self.agent_config.clone(),
+        );
+        let session = Arc::new(asupersync::sync::Mutex::new(Session::in_memory()));
+        AgentSession::new(agent, session, false, self.compaction_settings.clone())
+    }
+}
+
+/// Parse the `--prompt-file`, assigning each record without an explicit `id`
+/// its 0-based line index as a stable identifier.
+pub fn load_prompts(path: &Path) -> Result<Vec<BatchPrompt>> {
+    let file = File::open(path)
+        .map_err(|e| Error::validation(format!("Failed to open {}: {e}", path.display())))?;
+    let reader = BufReader::new(file);
+    let mut prompts = Vec::new();
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line
+            .map_err(|e| Error::validation(format!("Failed to read {}: {e}", path.display())))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut prompt: BatchPrompt = serde_json::from_str(&line).map_err(|e| {
+            Error::validation(format!(
+                "Invalid JSON on line {} of {}: {e}",
+                idx + 1,
+                path.display()
+            ))
+        })?;
+        if prompt.id.is_none() {
+            prompt.id = Some(idx.to_string());
+        }
+        prompts.push(prompt);
+    }
+    Ok(prompts)
+}
+
+/// Load the set of prompt ids already recorded as complete, for resume.
+fn load_checkpoint(path: &Path) -> Result<HashSet<String>> {
+    if !path.exists() {
+        return Ok(HashSet::new());
+    }
+    let file = File::open(path)
+        .map_err(|e| Error::validation(format!("Failed to open {}: {e}", path.display())))?;
+    let mut done = HashSet::new();
+    for line in BufReader::new(file).lines() {
+        let line = line
+            .map_err(|e| Error::validation(format!("Failed to read {}: {e}", path.display())))?;
+        let trimmed = line.trim();
+        if !trimmed.is_empty() {
+            done.insert(trimmed.to_string());
+        }
+    }
+    Ok(done)
+}
+
+/// Run a single prompt to completion and build its result record, never
+/// propagating agent errors — a failed prompt becomes a `BatchResult` with
+/// `error` set so the rest of the batch keeps going.
+async fn run_one(factory: &BatchSessionFactory, prompt: BatchPrompt) -> BatchResult {
+    let id = prompt.id.clone().unwrap_or_default();
+    let tool_trace: Arc<StdMutex<Vec<Value>>> = Arc::new(StdMutex::new(Vec::new()));
+    let tool_trace_for_events = Arc::clone(&tool_trace);
+    let started = Instant::now();
+
+    let mut session = factory.build_session();
+    let outcome = session
+        .run_text_with_abort(prompt.prompt.clone(), None, move |event: AgentEvent| {
+            if matches!(event, AgentEvent::ToolExecutionEnd { .. }) {
+                if let Ok(value) = serde_json::to_value(&event) {
+                    if let Ok(mut trace) = tool_trace_for_events.lock() {
+                        trace.push(value);
+                    }
+                }
+            }
+        })
+        .await;
+    let duration_ms = started.elapsed().as_millis();
+    let tool_trace = Arc::try_unwrap(tool_trace)
+        .map(|m| m.into_inner().unwrap_or_default())
+        .unwrap_or_default();
+
+    match outcome {
+        Ok(message) => {
+            let final_text = message
+                .content
+                .iter()
+                .filter_map(|block| match block {
+                    ContentBlock::Text(text) => Some(text.text.as_str()),
+                    _ => None,
+                })
+                .collect::<String>();
+            BatchResult {
+                id,
+                prompt: prompt.prompt,
+                final_text: Some(final_text),
+                tool_trace,
+                usage: serde_json::to_value(&message.usage).ok(),
+                duration_ms,
+                error: message.error_message,
+            }
+        }
+        Err(err) => BatchResult {
+            id,
+            prompt: prompt.prompt,
+            final_text: None,
+            tool_trace,
+            usage: None,
+            duration_ms,
+            error: Some(err.to_string()),
+        },
+    }
+}
+
+/// Run every uncompleted prompt from `request.prompt_file`, writing one
+/// result record per prompt to `request.output` and returning the totals.
+///
+/// Callers should check `BatchSummary::failed` to decide on a process exit code.
+pub async fn run_batch(request: &BatchRequest, factory: BatchSessionFactory) -> Result<BatchSummary> {
+    let prompts = load_prompts(&request.prompt_file)?;
+    let completed = match &request.checkpoint {
+        Some(path) => load_checkpoint(path)?,
+        None => HashSet::new(),
+    };
+
+    let mut summary = BatchSummary {
+        total: prompts.len(),
+        ..BatchSummary::default()
+    };
+
+    let mut output_file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&request.output)
+        .map_err(|e| {
+            Error::validation(format!(
+                "Failed to open output file {}: {e}",
+                request.output.display()
+            ))
+        })?;
+
+    let pending: Vec<BatchPrompt> = prompts
+        .into_iter()
+        .filter(|prompt| {
+            let id = prompt.id.as_deref().unwrap_or_default();
+            if completed.contains(id) {
+                summary.skipped += 1;
+                false
+            } else {
+                true
+            }
+        })
+        .collect();
+
+    let concurrency = request.concurrency.max(1);
+    for chunk in pending.chunks(concurrency) {
+        let results =
+            futures::future::join_all(chunk.iter().cloned().map(|prompt| {
+                let factory = factory.clone();
+                async move { run_one(&factory, prompt).await }
+            }))
+            .await;
+
+        for result in results {
+            if result.is_error() {
+                summary.failed += 1;
+            } else {
+                summary.succeeded += 1;
+            }
+
+            let line = serde_json::to_string(&result)
+                .map_err(|e| Error::validation(format!("Failed to serialize result: {e}")))?;
+            writeln!(output_file, "{line}")
+                .map_err(|e| Error::validation(format!("Failed to write output record: {e}")))?;
+
+            if let Some(checkpoint_path) = &request.checkpoint {
+                append_checkpoint(checkpoint_path, &result.id)?;
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+fn append_checkpoint(path: &Path, id: &str) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| {
+            Error::validation(format!("Failed to open checkpoint file {}: {e}", path.display()))
+        })?;
+    writeln!(file, "{id}")
+        .map_err(|e| Error::validation(format!("Failed to write checkpoint: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_prompts_assigns_line_index_when_id_missing() {
+        let mut file = tempfile::NamedTempFile::new().expect("tempfile");
+        writeln!(file, r#"{{"prompt": "first"}}"#).unwrap();
+        writeln!(file, r#"{{"id": "custom", "prompt": "second"}}"#).unwrap();
+
+        let prompts = load_prompts(file.path()).expect("load_prompts");
+        assert_eq!(prompts.len(), 2);
+        assert_eq!(prompts[0].id.as_deref(), Some("0"));
+        assert_eq!(prompts[1].id.as_deref(), Some("custom"));
+    }
+
+    #[test]
+    fn load_prompts_skips_blank_lines() {
+        let mut file = tempfile::NamedTempFile::new().expect("tempfile");
+        writeln!(file, r#"{{"prompt": "first"}}"#).unwrap();
+        writeln!(file).unwrap();
+        writeln!(file, r#"{{"prompt": "second"}}"#).unwrap();
+
+        let prompts = load_prompts(file.path()).expect("load_prompts");
+        assert_eq!(prompts.len(), 2);
+    }
+
+    #[test]
+    fn load_prompts_rejects_invalid_json() {
+        let mut file = tempfile::NamedTempFile::new().expect("tempfile");
+        writeln!(file, "not json").unwrap();
+
+        let err = load_prompts(file.path()).expect_err("should reject invalid JSON");
+        assert!(err.to_string().contains("Invalid JSON"));
+    }
+
+    #[test]
+    fn load_checkpoint_returns_empty_set_when_missing() {
+        let done = load_checkpoint(Path::new("/nonexistent/checkpoint.txt")).expect("load");
+        assert!(done.is_empty());
+    }
+
+    #[test]
+    fn load_checkpoint_reads_completed_ids() {
+        let mut file = tempfile::NamedTempFile::new().expect("tempfile");
+        writeln!(file, "a").unwrap();
+        writeln!(file, "b").unwrap();
+
+        let done = load_checkpoint(file.path()).expect("load_checkpoint");
+        assert_eq!(done.len(), 2);
+        assert!(done.contains("a"));
+        assert!(done.contains("b"));
+    }
+}
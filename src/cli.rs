@@ -302,6 +302,12 @@ pub struct Cli {
     #[arg(long)]
     pub append_system_prompt: Option<String>,
 
+    /// Select a named agent profile ("mode") from settings.json, binding a
+    /// system prompt, allowed tool set, and model (switchable at runtime
+    /// with `/mode <name>`)
+    #[arg(long)]
+    pub profile: Option<String>,
+
     // === Session Management ===
     /// Continue previous session
     #[arg(short = 'c', long)]
@@ -334,11 +340,27 @@ pub struct Cli {
     #[arg(long)]
     pub no_migrations: bool,
 
+    /// Bypass the on-disk provider response cache, even if one is configured
+    #[arg(long)]
+    pub no_cache: bool,
+
     // === Mode & Output ===
-    /// Output mode for print mode (text, json, rpc)
-    #[arg(long, value_parser = ["text", "json", "rpc"])]
+    /// Output mode for print mode (text, json, rpc, acp)
+    #[arg(long, value_parser = ["text", "json", "rpc", "acp"])]
     pub mode: Option<String>,
 
+    /// In `--mode rpc`, serve the JSON-RPC protocol over a socket instead of
+    /// stdio: `tcp://127.0.0.1:4317` or `unix:/path/to/pi.sock`
+    #[arg(long, value_name = "ADDR")]
+    pub listen: Option<String>,
+
+    /// Allow `--listen tcp://...` to bind a non-loopback address. The RPC
+    /// socket has no authentication, so anyone who can reach it gets the full
+    /// control plane (arbitrary tool execution, session control) -- this flag
+    /// is an explicit opt-in to exposing that beyond localhost.
+    #[arg(long)]
+    pub allow_remote_listen: bool,
+
     /// Non-interactive mode (process & exit)
     #[arg(short = 'p', long)]
     pub print: bool,
@@ -352,7 +374,7 @@ pub struct Cli {
     #[arg(long)]
     pub no_tools: bool,
 
-    /// Specific tools to enable (comma-separated: read,bash,edit,write,grep,find,ls)
+    /// Specific tools to enable (comma-separated: read,bash,edit,write,grep,find,ls,task)
     #[arg(long, default_value = "read,bash,edit,write")]
     pub tools: String,
 
@@ -369,6 +391,11 @@ pub struct Cli {
     #[arg(long, value_name = "PROFILE")]
     pub extension_policy: Option<String>,
 
+    /// Register an additional workspace root for extension fs access, as `label=path` or just
+    /// `path` (can use multiple times). Only takes effect with WASM extensions.
+    #[arg(long = "workspace-root", value_name = "LABEL=PATH", action = clap::ArgAction::Append)]
+    pub workspace_root: Vec<String>,
+
     /// Print the resolved extension policy with per-capability decisions and exit
     #[arg(long)]
     pub explain_extension_policy: bool,
@@ -530,6 +557,12 @@ mod tests {
         assert!(cli.no_migrations);
     }
 
+    #[test]
+    fn parse_no_cache() {
+        let cli = Cli::parse_from(["pi", "--no-cache"]);
+        assert!(cli.no_cache);
+    }
+
     #[test]
     fn parse_print_short_flag() {
         let cli = Cli::parse_from(["pi", "-p", "what is 2+2"]);
@@ -743,7 +776,7 @@ mod tests {
     fn parse_config_subcommand() {
         let cli = Cli::parse_from(["pi", "config"]);
         match cli.command {
-            Some(Commands::Config { show, paths, json }) => {
+            Some(Commands::Config { show, paths, json, .. }) => {
                 assert!(!show);
                 assert!(!paths);
                 assert!(!json);
@@ -756,7 +789,7 @@ mod tests {
     fn parse_config_show_flag() {
         let cli = Cli::parse_from(["pi", "config", "--show"]);
         match cli.command {
-            Some(Commands::Config { show, paths, json }) => {
+            Some(Commands::Config { show, paths, json, .. }) => {
                 assert!(show);
                 assert!(!paths);
                 assert!(!json);
@@ -769,7 +802,7 @@ mod tests {
     fn parse_config_paths_flag() {
         let cli = Cli::parse_from(["pi", "config", "--paths"]);
         match cli.command {
-            Some(Commands::Config { show, paths, json }) => {
+            Some(Commands::Config { show, paths, json, .. }) => {
                 assert!(!show);
                 assert!(paths);
                 assert!(!json);
@@ -782,7 +815,7 @@ mod tests {
     fn parse_config_json_flag() {
         let cli = Cli::parse_from(["pi", "config", "--json"]);
         match cli.command {
-            Some(Commands::Config { show, paths, json }) => {
+            Some(Commands::Config { show, paths, json, .. }) => {
                 assert!(!show);
                 assert!(!paths);
                 assert!(json);
@@ -791,6 +824,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_config_resolved_flag() {
+        let cli = Cli::parse_from(["pi", "config", "--resolved"]);
+        match cli.command {
+            Some(Commands::Config { resolved, .. }) => {
+                assert!(resolved);
+            }
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
     #[test]
     fn parse_update_index_subcommand() {
         let cli = Cli::parse_from(["pi", "update-index"]);
@@ -1082,6 +1126,7 @@ mod tests {
         assert!(!cli.verbose);
         assert!(!cli.no_session);
         assert!(!cli.no_migrations);
+        assert!(!cli.no_cache);
         assert!(!cli.no_tools);
         assert!(!cli.no_extensions);
         assert!(!cli.no_skills);
@@ -1583,6 +1628,10 @@ pub enum Commands {
         /// Print configuration details as JSON
         #[arg(long)]
         json: bool,
+        /// Print the fully merged configuration (global settings, discovered
+        /// `.pi/config.toml` files, and project settings) as JSON
+        #[arg(long)]
+        resolved: bool,
     },
 
     /// Diagnose environment health and extension compatibility
@@ -1611,6 +1660,22 @@ pub enum Commands {
         #[arg(long)]
         dry_run: bool,
     },
+
+    /// Run prompts from a JSONL file non-interactively, one result record per prompt
+    Run {
+        /// JSONL file of prompts (one `{"id": ..., "prompt": ...}` object per line)
+        #[arg(long)]
+        prompt_file: String,
+        /// JSONL file to write one structured result record per prompt
+        #[arg(long)]
+        output: String,
+        /// Maximum number of prompts to run concurrently
+        #[arg(long, default_value_t = 1)]
+        concurrency: usize,
+        /// File tracking completed prompt ids, to resume after a crash
+        #[arg(long)]
+        checkpoint: Option<String>,
+    },
 }
 
 impl Cli {
@@ -81,6 +81,11 @@ pub struct Config {
     #[serde(alias = "ghPath")]
     pub gh_path: Option<String>,
 
+    /// Run the post-write/edit formatter pipeline (rustfmt, prettier, black, ...) on files the
+    /// write/edit tools touch. Disabled by default.
+    #[serde(alias = "autoFormat")]
+    pub auto_format: Option<bool>,
+
     // Images
     pub images: Option<ImageSettings>,
 
@@ -114,6 +119,52 @@ pub struct Config {
     // Runtime Risk Controller
     #[serde(alias = "extensionRisk")]
     pub extension_risk: Option<ExtensionRiskConfig>,
+
+    // OpenTelemetry export
+    pub otel: Option<OtelSettings>,
+
+    // Outbound request rate limiting
+    #[serde(alias = "rateLimits")]
+    pub rate_limits: Option<RateLimitSettings>,
+
+    // On-disk provider response cache
+    #[serde(alias = "providerCache")]
+    pub provider_cache: Option<ProviderCacheSettings>,
+
+    /// Named agent profiles ("modes"), selectable at startup with `--profile`
+    /// and switched at runtime with `/mode <name>`.
+    pub profiles: Option<std::collections::HashMap<String, AgentProfile>>,
+}
+
+/// A named agent profile ("mode") binding a system prompt, allowed tool set,
+/// policy overrides, and model selection.
+///
+/// # Example (settings.json)
+///
+/// ```json
+/// {
+///   "profiles": {
+///     "plan": {
+///       "systemPrompt": "You are in planning mode. Do not edit files.",
+///       "tools": "read,grep,find,ls"
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgentProfile {
+    /// System prompt to use while this profile is active.
+    #[serde(alias = "systemPrompt")]
+    pub system_prompt: Option<String>,
+    /// Comma-separated tool names to enable while this profile is active
+    /// (same format as the `--tools` CLI flag).
+    pub tools: Option<String>,
+    /// Model to select while this profile is active, in `provider/model` form.
+    pub model: Option<String>,
+    /// Extension policy profile to apply while this profile is active.
+    #[serde(alias = "extensionPolicy")]
+    pub extension_policy: Option<String>,
 }
 
 /// Extension capability policy configuration.
@@ -247,6 +298,62 @@ pub struct RetrySettings {
     pub max_delay_ms: Option<u32>,
 }
 
+/// OpenTelemetry (OTLP) export configuration. Requires the `otel` build feature; ignored
+/// otherwise.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtelSettings {
+    pub enabled: Option<bool>,
+    /// OTLP/HTTP endpoint, e.g. `https://collector.example.com/v1/traces`.
+    pub endpoint: Option<String>,
+    /// Extra headers sent with every export request (e.g. collector auth tokens).
+    pub headers: Option<std::collections::HashMap<String, String>>,
+    #[serde(alias = "serviceName")]
+    pub service_name: Option<String>,
+}
+
+/// Outbound request rate limiting, enforced per `"{provider}/{model}"` key (falling back to a
+/// provider-wide key). Disabled unless `enabled = true`; see [`crate::rate_limiter::RateLimiter`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    pub enabled: Option<bool>,
+    #[serde(alias = "requestsPerMinute")]
+    pub requests_per_minute: Option<u32>,
+    #[serde(alias = "tokensPerMinute")]
+    pub tokens_per_minute: Option<u32>,
+    #[serde(alias = "maxConcurrent")]
+    pub max_concurrent: Option<u32>,
+    /// Per-provider or per-`"{provider}/{model}"` overrides of the top-level defaults.
+    pub overrides: Option<std::collections::HashMap<String, RateLimitOverride>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RateLimitOverride {
+    #[serde(alias = "requestsPerMinute")]
+    pub requests_per_minute: Option<u32>,
+    #[serde(alias = "tokensPerMinute")]
+    pub tokens_per_minute: Option<u32>,
+    #[serde(alias = "maxConcurrent")]
+    pub max_concurrent: Option<u32>,
+}
+
+/// On-disk response cache for deterministic provider calls; see
+/// [`crate::provider_cache::ProviderCache`]. Disabled unless `enabled = true`; always bypassed
+/// when `--no-cache` is passed regardless of this setting.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProviderCacheSettings {
+    pub enabled: Option<bool>,
+    /// Directory to store cache entries in; defaults to `<global_dir>/provider-cache`.
+    pub dir: Option<String>,
+    #[serde(alias = "ttlSecs")]
+    pub ttl_secs: Option<u64>,
+    #[serde(alias = "maxSizeBytes")]
+    pub max_size_bytes: Option<u64>,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ImageSettings {
@@ -400,13 +507,47 @@ impl Config {
             return Ok(config);
         }
 
-        let global = Self::load_from_path(&global_dir.join("settings.json"))?;
+        let mut merged = Self::load_from_path(&global_dir.join("settings.json"))?;
+        for toml_config in Self::discover_project_toml_configs(cwd)? {
+            merged = Self::merge(merged, toml_config);
+        }
         let project = Self::load_from_path(&cwd.join(Self::project_dir()).join("settings.json"))?;
-        let merged = Self::merge(global, project);
+        merged = Self::merge(merged, project);
         merged.emit_queue_mode_diagnostics();
         Ok(merged)
     }
 
+    /// Discover `.pi/config.toml` files from `cwd` up to the filesystem root,
+    /// returned in application order (furthest ancestor first, `cwd` last) so
+    /// that closer directories take precedence when merged in sequence.
+    fn discover_project_toml_configs(cwd: &Path) -> Result<Vec<Self>> {
+        let mut configs = Vec::new();
+        let mut current = cwd.to_path_buf();
+        loop {
+            let path = current.join(Self::project_dir()).join("config.toml");
+            if path.exists() {
+                configs.push(Self::load_from_toml_path(&path)?);
+            }
+            if !current.pop() {
+                break;
+            }
+        }
+        configs.reverse();
+        Ok(configs)
+    }
+
+    /// Load settings from a `.pi/config.toml` file.
+    fn load_from_toml_path(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        if content.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        toml::from_str(&content).map_err(|e| {
+            Error::config(format!("Failed to parse config file {}: {e}", path.display()))
+        })
+    }
+
     pub fn settings_path_with_roots(
         scope: SettingsScope,
         global_dir: &Path,
@@ -476,6 +617,7 @@ impl Config {
             shell_path: other.shell_path.or(base.shell_path),
             shell_command_prefix: other.shell_command_prefix.or(base.shell_command_prefix),
             gh_path: other.gh_path.or(base.gh_path),
+            auto_format: other.auto_format.or(base.auto_format),
 
             // Images
             images: merge_images(base.images, other.images),
@@ -505,6 +647,18 @@ impl Config {
 
             // Runtime Risk Controller
             extension_risk: merge_extension_risk(base.extension_risk, other.extension_risk),
+
+            // OpenTelemetry export
+            otel: other.otel.or(base.otel),
+
+            // Outbound request rate limiting
+            rate_limits: other.rate_limits.or(base.rate_limits),
+
+            // On-disk provider response cache
+            provider_cache: other.provider_cache.or(base.provider_cache),
+
+            // Agent Profiles
+            profiles: other.profiles.or(base.profiles),
         }
     }
 
@@ -575,6 +729,11 @@ impl Config {
             .unwrap_or(true)
     }
 
+    /// Whether the post-write/edit formatter pipeline should run (default: false).
+    pub fn auto_format(&self) -> bool {
+        self.auto_format.unwrap_or(false)
+    }
+
     /// Whether to check for version updates on startup (default: true).
     pub fn should_check_for_updates(&self) -> bool {
         self.check_for_updates.unwrap_or(true)
@@ -1299,6 +1458,49 @@ mod tests {
         assert_eq!(config.theme.as_deref(), Some("global"));
     }
 
+    #[test]
+    fn load_merges_project_toml_between_global_and_project_json() {
+        let temp = TempDir::new().expect("create tempdir");
+        let cwd = temp.path().join("cwd");
+        let global_dir = temp.path().join("global");
+        write_file(
+            &global_dir.join("settings.json"),
+            r#"{ "default_provider": "anthropic", "theme": "global" }"#,
+        );
+        write_file(
+            &cwd.join(".pi/config.toml"),
+            "default_provider = \"google\"\ndefault_model = \"toml-model\"\n",
+        );
+        write_file(
+            &cwd.join(".pi/settings.json"),
+            r#"{ "default_model": "project" }"#,
+        );
+
+        let config = Config::load_with_roots(None, &global_dir, &cwd).expect("load config");
+        assert_eq!(config.default_provider.as_deref(), Some("google"));
+        assert_eq!(config.default_model.as_deref(), Some("project"));
+        assert_eq!(config.theme.as_deref(), Some("global"));
+    }
+
+    #[test]
+    fn load_merges_project_toml_from_ancestor_directories() {
+        let temp = TempDir::new().expect("create tempdir");
+        let cwd = temp.path().join("workspace").join("nested");
+        let global_dir = temp.path().join("global");
+        write_file(
+            &temp.path().join("workspace").join(".pi/config.toml"),
+            "theme = \"workspace\"\ndefault_model = \"workspace-model\"\n",
+        );
+        write_file(
+            &cwd.join(".pi/config.toml"),
+            "default_model = \"nested-model\"\n",
+        );
+
+        let config = Config::load_with_roots(None, &global_dir, &cwd).expect("load config");
+        assert_eq!(config.theme.as_deref(), Some("workspace"));
+        assert_eq!(config.default_model.as_deref(), Some("nested-model"));
+    }
+
     #[test]
     fn load_merges_nested_structs_instead_of_overriding() {
         let temp = TempDir::new().expect("create tempdir");
@@ -546,6 +546,14 @@ fn is_known_config_key(key: &str) -> bool {
             | "session_durability"
             | "markdown"
             | "queueMode"
+            | "otel"
+            | "rateLimits"
+            | "rate_limits"
+            | "providerCache"
+            | "provider_cache"
+            | "autoFormat"
+            | "auto_format"
+            | "profiles"
     )
 }
 
@@ -9677,6 +9677,21 @@ impl FsScopes {
         })
     }
 
+    /// Full read/write access to every root of a multi-root workspace (e.g.
+    /// an app repo plus one or more shared library checkouts).
+    pub fn for_workspace(roots: &crate::workspace::WorkspaceRoots) -> Result<Self> {
+        if roots.is_empty() {
+            return Err(Error::validation("Workspace has no registered roots"));
+        }
+        let canonical: Vec<PathBuf> = roots.paths().map(Path::to_path_buf).collect();
+        Ok(Self {
+            read_declared: true,
+            write_declared: true,
+            read_roots: canonical.clone(),
+            write_roots: canonical,
+        })
+    }
+
     pub fn from_manifest(manifest: Option<&CapabilityManifest>, cwd: &Path) -> Result<Self> {
         let Some(manifest) = manifest else {
             return Self::least_privilege_for_cwd(cwd);
@@ -9750,6 +9765,7 @@ pub struct FsConnector {
     cwd: PathBuf,
     policy: ExtensionPolicy,
     scopes: FsScopes,
+    workspace: Option<crate::workspace::WorkspaceRoots>,
 }
 
 impl FsConnector {
@@ -9759,9 +9775,22 @@ impl FsConnector {
             cwd,
             policy,
             scopes,
+            workspace: None,
         })
     }
 
+    /// Attach workspace root labels so fs events report which root of a
+    /// multi-root workspace a path belongs to instead of an opaque hash.
+    #[must_use]
+    pub fn with_workspace(mut self, workspace: crate::workspace::WorkspaceRoots) -> Self {
+        self.workspace = Some(workspace);
+        self
+    }
+
+    fn root_label(&self, path: &Path) -> Option<&str> {
+        self.workspace.as_ref()?.label_for(path)
+    }
+
     pub fn handle_host_call(&self, call: &HostCallPayload) -> HostResultPayload {
         if !call.method.trim().eq_ignore_ascii_case("fs") {
             return HostResultPayload {
@@ -9866,6 +9895,7 @@ impl FsConnector {
                 capability = capability,
                 path_hash = %hash_path(&canonical_target),
                 scope_roots = ?root_hashes,
+                workspace_root = self.root_label(&canonical_target),
                 "Denied fs operation outside allowlist",
             );
             return Err(HostCallError {
@@ -9889,6 +9919,7 @@ impl FsConnector {
             capability = capability,
             path_hash = %hash_path(&canonical_target),
             scope_root = %matched_root_hash,
+            workspace_root = self.root_label(&canonical_target),
             "Executing fs operation",
         );
 
@@ -12903,6 +12934,14 @@ mod wasm_host {
             })
         }
 
+        /// Reconfigure the fs connector for full read/write access across every root of a
+        /// multi-root workspace, instead of the default least-privilege single-`cwd` scope.
+        pub(super) fn with_workspace(mut self, roots: &crate::workspace::WorkspaceRoots) -> Result<Self> {
+            let scopes = FsScopes::for_workspace(roots)?;
+            self.fs = FsConnector::new(&self.cwd, self.policy.clone(), scopes)?.with_workspace(roots.clone());
+            Ok(self)
+        }
+
         fn env_allowlist_from_manifest(manifest: Option<&CapabilityManifest>) -> BTreeSet<String> {
             let Some(manifest) = manifest else {
                 return BTreeSet::new();
@@ -14875,6 +14914,7 @@ pub struct WasmExtensionHost {
     policy: ExtensionPolicy,
     cwd: PathBuf,
     engine: wasmtime::Engine,
+    workspace: Option<crate::workspace::WorkspaceRoots>,
 }
 
 #[cfg(feature = "wasm-host")]
@@ -14891,9 +14931,18 @@ impl WasmExtensionHost {
             policy,
             cwd: cwd.to_path_buf(),
             engine,
+            workspace: None,
         })
     }
 
+    /// Scope every extension loaded through this host to a multi-root workspace instead of just
+    /// `cwd`, so fs host-calls can read/write across all registered roots.
+    #[must_use]
+    pub fn with_workspace(mut self, workspace: crate::workspace::WorkspaceRoots) -> Self {
+        self.workspace = Some(workspace);
+        self
+    }
+
     pub const fn policy(&self) -> &ExtensionPolicy {
         &self.policy
     }
@@ -14911,12 +14960,11 @@ impl WasmExtensionHost {
     }
 
     pub async fn instantiate(&self, extension: &WasmExtension) -> Result<wasm_host::Instance> {
-        wasm_host::Instance::instantiate(
-            &self.engine,
-            &extension.path,
-            wasm_host::HostState::new(self.policy.clone(), self.cwd.clone())?,
-        )
-        .await
+        let mut state = wasm_host::HostState::new(self.policy.clone(), self.cwd.clone())?;
+        if let Some(workspace) = &self.workspace {
+            state = state.with_workspace(workspace)?;
+        }
+        wasm_host::Instance::instantiate(&self.engine, &extension.path, state).await
     }
 
     async fn instantiate_with(
@@ -14925,17 +14973,12 @@ impl WasmExtensionHost {
         tools: Arc<ToolRegistry>,
         manager: Option<ExtensionManagerHandle>,
     ) -> Result<wasm_host::Instance> {
-        wasm_host::Instance::instantiate(
-            &self.engine,
-            &extension.path,
-            wasm_host::HostState::new_with_tools(
-                self.policy.clone(),
-                self.cwd.clone(),
-                tools,
-                manager,
-            )?,
-        )
-        .await
+        let mut state =
+            wasm_host::HostState::new_with_tools(self.policy.clone(), self.cwd.clone(), tools, manager)?;
+        if let Some(workspace) = &self.workspace {
+            state = state.with_workspace(workspace)?;
+        }
+        wasm_host::Instance::instantiate(&self.engine, &extension.path, state).await
     }
 }
 
@@ -15024,6 +15067,8 @@ pub enum ExtensionEventName {
     SessionBeforeCompact,
     /// Session compacted.
     SessionCompact,
+    /// Agent profile ("mode") changed.
+    ModeChanged,
 }
 
 impl std::fmt::Display for ExtensionEventName {
@@ -15050,6 +15095,7 @@ impl std::fmt::Display for ExtensionEventName {
             Self::SessionFork => "session_fork",
             Self::SessionBeforeCompact => "session_before_compact",
             Self::SessionCompact => "session_compact",
+            Self::ModeChanged => "mode_changed",
         };
         write!(f, "{name}")
     }
@@ -23604,6 +23650,9 @@ struct ExtensionManagerInner {
     active_tools: Option<Vec<String>>,
     providers: Vec<Value>,
     flags: Vec<Value>,
+    /// User-defined slash commands loaded from `~/.pi/commands/` and
+    /// `.pi/commands/`, merged into `list_commands()` alongside extension commands.
+    user_commands: Vec<Value>,
     cwd: Option<String>,
     model_registry_values: HashMap<String, String>,
     current_provider: Option<String>,
@@ -23986,7 +24035,8 @@ impl ExtensionManager {
         flags
     }
 
-    /// Pre-compute slash command list from all extensions.
+    /// Pre-compute slash command list from all extensions, plus any
+    /// user-defined commands registered via [`ExtensionManager::set_user_commands`].
     fn precompute_all_commands(inner: &ExtensionManagerInner) -> Vec<Value> {
         let mut commands = Vec::new();
         for ext in &inner.extensions {
@@ -24002,6 +24052,7 @@ impl ExtensionManager {
                 }));
             }
         }
+        commands.extend(inner.user_commands.iter().cloned());
         commands
     }
 
@@ -24056,13 +24107,21 @@ impl ExtensionManager {
 
     /// Pre-compute normalized command names for O(1) `has_command()` lookup.
     fn precompute_command_names(inner: &ExtensionManagerInner) -> HashSet<String> {
-        inner
+        let mut names: HashSet<String> = inner
             .extensions
             .iter()
             .flat_map(|ext| ext.slash_commands.iter())
             .filter_map(extract_slash_command_name)
             .map(|cmd| normalize_command(&cmd))
-            .collect()
+            .collect();
+        names.extend(
+            inner
+                .user_commands
+                .iter()
+                .filter_map(extract_slash_command_name)
+                .map(|cmd| normalize_command(&cmd)),
+        );
+        names
     }
 
     /// Atomically publish a new snapshot, replacing the old one.
@@ -26853,6 +26912,16 @@ impl ExtensionManager {
         self.refresh_snapshot_with_guard_release(guard);
     }
 
+    /// Register user-defined commands (e.g. loaded from `~/.pi/commands/` and
+    /// `.pi/commands/` markdown files) so they appear in `list_commands()`
+    /// alongside extension-provided commands.
+    #[allow(clippy::significant_drop_tightening)]
+    pub fn set_user_commands(&self, commands: Vec<Value>) {
+        let mut guard = self.inner.lock().unwrap();
+        guard.user_commands = commands;
+        self.refresh_snapshot_with_guard_release(guard);
+    }
+
     /// Lock-free: reads from the RCU snapshot.
     pub fn current_model(&self) -> (Option<String>, Option<String>) {
         let snap = self.read_snapshot();
@@ -0,0 +1,160 @@
+//! Post-write formatter pipeline.
+//!
+//! When enabled, [`FormatterPipeline`] runs a configured formatter binary
+//! (rustfmt, prettier, black, ...) on a file immediately after the agent
+//! writes or edits it, and reports whether the file changed (or why the
+//! formatter failed). The edit and write tools add the formatter's name or
+//! error to their result; no diff of the formatting change is computed.
+
+use crate::error::{Error, Result};
+use std::path::Path;
+use std::process::Command;
+
+/// One configured formatter: a binary invoked with `args` plus the file
+/// path, matched against files by extension.
+#[derive(Debug, Clone)]
+pub struct FormatterSpec {
+    pub name: &'static str,
+    pub extensions: &'static [&'static str],
+    pub program: &'static str,
+    pub args: &'static [&'static str],
+}
+
+/// Formatters shipped out of the box, mirroring the tools most repos in
+/// this ecosystem already depend on.
+pub const BUILTIN_FORMATTERS: &[FormatterSpec] = &[
+    FormatterSpec {
+        name: "rustfmt",
+        extensions: &["rs"],
+        program: "rustfmt",
+        args: &["--edition", "2021"],
+    },
+    FormatterSpec {
+        name: "prettier",
+        extensions: &["ts", "tsx", "js", "jsx", "json", "css", "md", "yaml", "yml"],
+        program: "prettier",
+        args: &["--write"],
+    },
+    FormatterSpec {
+        name: "black",
+        extensions: &["py"],
+        program: "black",
+        args: &[],
+    },
+];
+
+/// Outcome of running a formatter on a single file.
+#[derive(Debug, Clone)]
+pub struct FormatResult {
+    pub formatter: &'static str,
+    /// The file's content after formatting, when formatting changed it (not a diff).
+    pub diff: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Runs configured formatters against files the agent modifies.
+#[derive(Debug, Clone)]
+pub struct FormatterPipeline {
+    enabled: bool,
+    specs: Vec<FormatterSpec>,
+}
+
+impl FormatterPipeline {
+    #[must_use]
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            specs: BUILTIN_FORMATTERS.to_vec(),
+        }
+    }
+
+    fn spec_for(&self, path: &Path) -> Option<&FormatterSpec> {
+        let ext = path.extension()?.to_str()?;
+        self.specs
+            .iter()
+            .find(|spec| spec.extensions.contains(&ext))
+    }
+
+    /// Format `path` in place if a matching formatter is configured and
+    /// enabled. Returns `Ok(None)` when no formatter applies, so callers
+    /// can silently skip files like `.gitignore` without treating that as
+    /// an error.
+    pub fn run(&self, path: &Path) -> Result<Option<FormatResult>> {
+        if !self.enabled {
+            return Ok(None);
+        }
+        let Some(spec) = self.spec_for(path) else {
+            return Ok(None);
+        };
+
+        let before = std::fs::read_to_string(path).map_err(|err| {
+            Error::tool("format", format!("Failed to read {}: {err}", path.display()))
+        })?;
+
+        let output = Command::new(spec.program)
+            .args(spec.args)
+            .arg(path)
+            .output();
+
+        let output = match output {
+            Ok(output) => output,
+            Err(err) => {
+                return Ok(Some(FormatResult {
+                    formatter: spec.name,
+                    diff: None,
+                    error: Some(format!("Failed to run {}: {err}", spec.program)),
+                }));
+            }
+        };
+
+        if !output.status.success() {
+            return Ok(Some(FormatResult {
+                formatter: spec.name,
+                diff: None,
+                error: Some(String::from_utf8_lossy(&output.stderr).into_owned()),
+            }));
+        }
+
+        let after = std::fs::read_to_string(path).map_err(|err| {
+            Error::tool("format", format!("Failed to reread {}: {err}", path.display()))
+        })?;
+
+        Ok(Some(FormatResult {
+            formatter: spec.name,
+            diff: (before != after).then_some(after),
+            error: None,
+        }))
+    }
+}
+
+impl Default for FormatterPipeline {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_pipeline_is_a_noop() {
+        let pipeline = FormatterPipeline::new(false);
+        let result = pipeline.run(Path::new("main.rs")).expect("run");
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn unmatched_extension_is_skipped() {
+        let pipeline = FormatterPipeline::new(true);
+        assert!(pipeline.spec_for(Path::new("README")).is_none());
+        assert!(pipeline.spec_for(Path::new("notes.txt")).is_none());
+    }
+
+    #[test]
+    fn matches_formatter_by_extension() {
+        let pipeline = FormatterPipeline::new(true);
+        let spec = pipeline.spec_for(Path::new("src/main.rs")).expect("spec");
+        assert_eq!(spec.name, "rustfmt");
+    }
+}
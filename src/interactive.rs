@@ -56,7 +56,7 @@ use crate::providers;
 use crate::resources::{DiagnosticKind, ResourceCliOptions, ResourceDiagnostic, ResourceLoader};
 use crate::session::{Session, SessionEntry, SessionMessage, bash_execution_to_text};
 use crate::theme::{Theme, TuiStyles};
-use crate::tools::{process_file_arguments, resolve_read_path};
+use crate::tools::{ToolRegistry, process_file_arguments, resolve_read_path};
 
 #[cfg(all(feature = "clipboard", feature = "image-resize"))]
 use arboard::Clipboard as ArboardClipboard;
@@ -1064,6 +1064,7 @@ pub async fn run_interactive(
     extensions: Option<ExtensionManager>,
     cwd: PathBuf,
     runtime_handle: RuntimeHandle,
+    extension_degradation: Option<crate::agent::ExtensionDegradationNotice>,
 ) -> anyhow::Result<()> {
     let show_hardware_cursor = config.show_hardware_cursor.unwrap_or_else(|| {
         std::env::var("PI_HARDWARE_CURSOR")
@@ -1132,6 +1133,7 @@ pub async fn run_interactive(
         None,
         messages,
         usage,
+        extension_degradation,
     );
 
     Program::new(app)
@@ -1381,6 +1383,9 @@ pub struct PiApp {
     git_branch: Option<String>,
     // Startup banner shown in an empty conversation.
     startup_welcome: String,
+    // Persistent header banner shown while extension loading was degraded;
+    // dismissed by any key press (see `ExtensionDegradationNotice`).
+    extension_degradation: Option<crate::agent::ExtensionDegradationNotice>,
 }
 
 impl PiApp {
@@ -1405,6 +1410,7 @@ impl PiApp {
         keybindings_override: Option<KeyBindings>,
         messages: Vec<ConversationMessage>,
         total_usage: Usage,
+        extension_degradation: Option<crate::agent::ExtensionDegradationNotice>,
     ) -> Self {
         // Get terminal size
         let (term_width, term_height) =
@@ -1592,6 +1598,7 @@ impl PiApp {
             render_buffers: RenderBuffers::new(),
             git_branch,
             startup_welcome,
+            extension_degradation,
         };
 
         if let Some(manager) = app.extensions.clone() {
@@ -1793,6 +1800,9 @@ impl PiApp {
         if let Some(key) = msg.downcast_ref::<KeyMsg>() {
             // Clear status message on any key press
             self.status_message = None;
+            if let Some(notice) = self.extension_degradation.as_mut() {
+                notice.dismiss();
+            }
             if key.key_type != KeyType::Esc {
                 self.last_escape_time = None;
             }
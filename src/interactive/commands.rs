@@ -32,6 +32,7 @@ pub enum SlashCommand {
     Compact,
     Reload,
     Share,
+    Mode,
 }
 
 impl SlashCommand {
@@ -69,6 +70,7 @@ impl SlashCommand {
             "/compact" => Self::Compact,
             "/reload" => Self::Reload,
             "/share" => Self::Share,
+            "/mode" => Self::Mode,
             _ => return None,
         };
 
@@ -101,6 +103,7 @@ impl SlashCommand {
   /compact [notes]   - Compact older context with optional instructions
   /reload            - Reload skills/prompts from disk
   /share             - Upload session HTML to a secret GitHub gist and show URL
+  /mode [name]       - Show or switch the active agent profile (system prompt/tools/model)
   /exit, /quit, /q   - Exit Pi
 
   Tips:
@@ -492,6 +495,19 @@ fn split_provider_model_spec(model_spec: &str) -> Option<(&str, &str)> {
     Some((provider, model_id))
 }
 
+/// Resolve a `provider/model` spec (as used by [`AgentProfile::model`](crate::config::AgentProfile))
+/// against the currently known models.
+fn resolve_model_entry_by_spec(available_models: &[ModelEntry], model_spec: &str) -> Option<ModelEntry> {
+    let (provider, model_id) = split_provider_model_spec(model_spec)?;
+    available_models
+        .iter()
+        .find(|entry| {
+            provider_ids_match(&entry.model.provider, provider)
+                && entry.model.id.eq_ignore_ascii_case(model_id)
+        })
+        .cloned()
+}
+
 pub fn resolve_scoped_model_entries(
     patterns: &[String],
     available_models: &[ModelEntry],
@@ -1567,6 +1583,7 @@ impl PiApp {
             SlashCommand::Compact => self.handle_slash_compact(args),
             SlashCommand::Reload => self.handle_slash_reload(),
             SlashCommand::Share => self.handle_slash_share(args),
+            SlashCommand::Mode => self.handle_slash_mode(args),
         }
     }
 
@@ -1880,31 +1897,43 @@ result in account suspension/ban. Prefer using an Anthropic API key (ANTHROPIC_A
 
         let next = matches.into_iter().next().expect("matches is non-empty");
 
-        let resolved_key_opt = resolve_model_key_from_default_auth(&next);
-        if model_requires_configured_credential(&next) && resolved_key_opt.is_none() {
-            self.status_message = Some(format!(
-                "Missing credentials for provider {}. Run /login {}.",
-                next.model.provider, next.model.provider
-            ));
-            return None;
-        }
-
         if model_entry_matches(&next, &self.model_entry) {
             self.status_message = Some(format!("Current model: {}", self.model));
             return None;
         }
 
-        let provider_impl = match providers::create_provider(&next, self.extensions.as_ref()) {
-            Ok(provider_impl) => provider_impl,
-            Err(err) => {
-                self.status_message = Some(err.to_string());
-                return None;
-            }
-        };
+        self.status_message = Some(match self.switch_active_model(&next) {
+            Ok(model) => format!("Switched model: {model}"),
+            Err(err) => err,
+        });
+        None
+    }
+
+    /// Swap the agent's active provider/model to `next`, updating session state and
+    /// the shared model selector state the same way the RPC `set_model` command does.
+    /// Returns the new `provider/model` label on success, or a user-facing error message.
+    fn switch_active_model(&mut self, next: &ModelEntry) -> Result<String, String> {
+        let resolved_key_opt = resolve_model_key_from_default_auth(next);
+        if model_requires_configured_credential(next) && resolved_key_opt.is_none() {
+            return Err(format!(
+                "Missing credentials for provider {}. Run /login {}.",
+                next.model.provider, next.model.provider
+            ));
+        }
+
+        let provider_impl = providers::create_provider(next, self.extensions.as_ref())
+            .map_err(|err| err.to_string())?;
+        let provider_impl =
+            providers::apply_rate_limit(provider_impl, next, self.config.rate_limits.as_ref());
+        let provider_impl = providers::apply_provider_cache(
+            provider_impl,
+            self.config.provider_cache.as_ref(),
+            false,
+            &Config::global_dir().join("provider-cache"),
+        );
 
         let Ok(mut agent_guard) = self.agent.try_lock() else {
-            self.status_message = Some("Agent busy; try again".to_string());
-            return None;
+            return Err("Agent busy; try again".to_string());
         };
         agent_guard.set_provider(provider_impl);
         agent_guard
@@ -1918,8 +1947,7 @@ result in account suspension/ban. Prefer using an Anthropic API key (ANTHROPIC_A
         drop(agent_guard);
 
         let Ok(mut session_guard) = self.session.try_lock() else {
-            self.status_message = Some("Session busy; try again".to_string());
-            return None;
+            return Err("Session busy; try again".to_string());
         };
         session_guard.header.provider = Some(next.model.provider.clone());
         session_guard.header.model_id = Some(next.model.id.clone());
@@ -1930,7 +1958,7 @@ result in account suspension/ban. Prefer using an Anthropic API key (ANTHROPIC_A
         if !self
             .available_models
             .iter()
-            .any(|entry| model_entry_matches(entry, &next))
+            .any(|entry| model_entry_matches(entry, next))
         {
             self.available_models.push(next.clone());
         }
@@ -1939,9 +1967,7 @@ result in account suspension/ban. Prefer using an Anthropic API key (ANTHROPIC_A
             *guard = next.clone();
         }
         self.model = format!("{}/{}", next.model.provider, next.model.id);
-
-        self.status_message = Some(format!("Switched model: {}", self.model));
-        None
+        Ok(self.model.clone())
     }
 
     pub(super) fn handle_slash_thinking(&mut self, args: &str) -> Option<Cmd> {
@@ -1982,6 +2008,80 @@ result in account suspension/ban. Prefer using an Anthropic API key (ANTHROPIC_A
         None
     }
 
+    /// Show or switch the active agent profile ("mode"): system prompt, tools, and model.
+    pub(super) fn handle_slash_mode(&mut self, args: &str) -> Option<Cmd> {
+        let name = args.trim();
+        if name.is_empty() {
+            let current = self
+                .session
+                .try_lock()
+                .ok()
+                .and_then(|guard| guard.header.active_profile.clone());
+            self.status_message = Some(current.map_or_else(
+                || "No active profile (use /mode <name> to switch)".to_string(),
+                |name| format!("Active profile: {name}"),
+            ));
+            return None;
+        }
+
+        let Some(profile) = self
+            .config
+            .profiles
+            .as_ref()
+            .and_then(|profiles| profiles.get(name))
+            .cloned()
+        else {
+            self.status_message = Some(format!("Unknown agent profile: {name}"));
+            return None;
+        };
+        let name = name.to_string();
+
+        let Ok(mut session_guard) = self.session.try_lock() else {
+            self.status_message = Some("Session busy; try again".to_string());
+            return None;
+        };
+        session_guard.header.active_profile = Some(name.clone());
+        drop(session_guard);
+        self.spawn_save_session();
+
+        if let Ok(mut agent_guard) = self.agent.try_lock() {
+            agent_guard.set_system_prompt(profile.system_prompt.clone());
+            if let Some(tools_spec) = &profile.tools {
+                let enabled: Vec<&str> = tools_spec.split(',').map(str::trim).collect();
+                agent_guard.set_tools(ToolRegistry::new(&enabled, &self.cwd, Some(&self.config)));
+            }
+        }
+
+        let mut status = format!("Switched to profile: {name}");
+        if let Some(model_spec) = &profile.model {
+            match resolve_model_entry_by_spec(&self.available_models, model_spec) {
+                Some(next) => match self.switch_active_model(&next) {
+                    Ok(model) => status = format!("Switched to profile: {name} (model: {model})"),
+                    Err(err) => {
+                        status = format!("Switched to profile: {name}, but failed to switch model: {err}");
+                    }
+                },
+                None => {
+                    status = format!(
+                        "Switched to profile: {name}, but profile model not found: {model_spec}"
+                    );
+                }
+            }
+        }
+
+        if let Some(extensions) = self.extensions.clone() {
+            let mode_name = name;
+            self.runtime_handle.spawn(async move {
+                let _ = extensions
+                    .dispatch_event(ExtensionEventName::ModeChanged, Some(json!({ "mode": mode_name })))
+                    .await;
+            });
+        }
+
+        self.status_message = Some(status);
+        None
+    }
+
     #[allow(clippy::too_many_lines)]
     pub(super) fn handle_slash_scoped_models(&mut self, args: &str) -> Option<Cmd> {
         let value = args.trim();
@@ -2381,6 +2481,20 @@ mod tests {
         assert!(!super::provider_ids_match("openai", "anthropic"));
     }
 
+    #[test]
+    fn resolve_model_entry_by_spec_matches_provider_alias_and_case() {
+        let models = vec![test_model_entry("openrouter", "openai/gpt-4o-mini")];
+        let resolved =
+            super::resolve_model_entry_by_spec(&models, "open-router/OPENAI/GPT-4O-MINI");
+        assert!(resolved.is_some());
+    }
+
+    #[test]
+    fn resolve_model_entry_by_spec_rejects_unknown_model() {
+        let models = vec![test_model_entry("openrouter", "openai/gpt-4o-mini")];
+        assert!(super::resolve_model_entry_by_spec(&models, "openrouter/does-not-exist").is_none());
+    }
+
     #[test]
     fn normalize_auth_provider_input_maps_kimi_code_alias() {
         assert_eq!(
@@ -1020,6 +1020,7 @@ mod tests {
             Some(KeyBindings::new()),
             Vec::new(),
             Usage::default(),
+            None,
         )
     }
 
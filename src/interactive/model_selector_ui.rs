@@ -454,6 +454,7 @@ mod tests {
             Some(KeyBindings::new()),
             Vec::new(),
             Usage::default(),
+            None,
         )
     }
 
@@ -609,6 +609,24 @@ impl PiApp {
             self.styles.muted.render(&hints_line),
             self.styles.muted.render(&resources_line),
         );
+
+        if let Some(notice) = &self.extension_degradation
+            && !notice.is_dismissed()
+        {
+            let caps = if notice.degraded_capabilities.is_empty() {
+                "extensions".to_string()
+            } else {
+                notice.degraded_capabilities.join(", ")
+            };
+            let banner = truncate(
+                &format!(
+                    "⚠ Extension runtime unavailable ({caps} disabled): {}. Press any key to dismiss.",
+                    notice.reason
+                ),
+                max_width,
+            );
+            let _ = writeln!(output, "  {}", self.styles.warning.render(&banner));
+        }
     }
 
     pub(super) fn render_header(&self) -> String {
@@ -51,11 +51,13 @@ extern crate self as pi;
 #[global_allocator]
 static GLOBAL_ALLOCATOR: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
+pub mod acp;
 pub mod agent;
 pub mod agent_cx;
 pub mod app;
 pub mod auth;
 pub mod autocomplete;
+pub mod batch;
 pub mod buffer_shim;
 pub mod cli;
 pub mod compaction;
@@ -83,6 +85,7 @@ pub mod extension_validation;
 pub mod extensions;
 pub mod extensions_js;
 pub mod flake_classifier;
+pub mod formatters;
 pub mod hostcall_amac;
 pub mod hostcall_io_uring_lane;
 pub mod hostcall_queue;
@@ -98,14 +101,19 @@ pub mod migrations;
 pub mod model;
 pub mod model_selector;
 pub mod models;
+#[cfg(feature = "otel")]
+pub mod otel;
 pub mod package_manager;
 pub mod perf_build;
 pub mod permissions;
 #[cfg(feature = "wasm-host")]
 pub mod pi_wasm;
 pub mod provider;
+pub mod provider_cache;
 pub mod provider_metadata;
 pub mod providers;
+pub mod qa;
+pub mod rate_limiter;
 pub mod resources;
 pub mod rpc;
 pub mod scheduler;
@@ -124,6 +132,7 @@ pub mod tools;
 pub mod tui;
 pub mod vcr;
 pub mod version_check;
+pub mod workspace;
 
 pub use error::{Error, Result as PiResult};
 pub use extension_dispatcher::ExtensionDispatcher;
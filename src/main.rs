@@ -152,7 +152,11 @@ fn main_impl() -> Result<()> {
                 )?;
                 return Ok(());
             }
-            cli::Commands::Config { show, paths, json } => {
+            cli::Commands::Config { show, paths, json, resolved } => {
+                if *resolved {
+                    handle_config_resolved_fast(&cwd)?;
+                    return Ok(());
+                }
                 if *paths && !*show && !*json {
                     handle_config_paths_fast(&cwd);
                     return Ok(());
@@ -729,6 +733,25 @@ async fn run(
 ) -> Result<()> {
     let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
 
+    let batch_request = if let Some(cli::Commands::Run {
+        prompt_file,
+        output,
+        concurrency,
+        checkpoint,
+    }) = &cli.command
+    {
+        let request = pi::batch::BatchRequest {
+            prompt_file: PathBuf::from(prompt_file),
+            output: PathBuf::from(output),
+            concurrency: *concurrency,
+            checkpoint: checkpoint.as_ref().map(PathBuf::from),
+        };
+        cli.command = None;
+        Some(request)
+    } else {
+        None
+    };
+
     if let Some(command) = cli.command.take() {
         handle_subcommand(command, &cwd).await?;
         return Ok(());
@@ -746,6 +769,7 @@ async fn run(
         // Theme already validated above
         config.theme = Some(theme_spec.to_string());
     }
+    pi::app::apply_agent_profile(&mut cli, &config)?;
     spawn_session_index_maintenance();
     let package_manager = PackageManager::new(cwd.clone());
     let resource_cli = ResourceCliOptions {
@@ -934,7 +958,7 @@ async fn run(
         return Ok(());
     }
 
-    if cli.mode.as_deref() != Some("rpc") {
+    if !matches!(cli.mode.as_deref(), Some("rpc") | Some("acp")) {
         let stdin_content = read_piped_stdin()?;
         pi::app::apply_piped_stdin(&mut cli, stdin_content);
     }
@@ -992,6 +1016,9 @@ async fn run(
     }
 
     let mut session = Box::pin(Session::new(&cli, &config)).await?;
+    if let Some(profile) = &cli.profile {
+        session.header.active_profile = Some(profile.clone());
+    }
 
     let (selection, resolved_key) = loop {
         scoped_models = if scoped_patterns.is_empty() {
@@ -1088,6 +1115,13 @@ async fn run(
     );
     let provider =
         providers::create_provider(&selection.model_entry, None).map_err(anyhow::Error::new)?;
+    let provider = providers::apply_rate_limit(provider, &selection.model_entry, config.rate_limits.as_ref());
+    let provider = providers::apply_provider_cache(
+        provider,
+        config.provider_cache.as_ref(),
+        cli.no_cache,
+        &global_dir.join("provider-cache"),
+    );
     let stream_options = pi::app::build_stream_options(&config, resolved_key, &selection, &session);
     let agent_config = AgentConfig {
         system_prompt: Some(system_prompt),
@@ -1096,8 +1130,6 @@ async fn run(
         block_images: config.image_block_images(),
     };
 
-    let tools = ToolRegistry::new(&enabled_tools, &cwd, Some(&config));
-    let session_arc = Arc::new(Mutex::new(session));
     let context_window_tokens = if selection.model_entry.model.context_window == 0 {
         tracing::warn!(
             "Model {} reported context_window=0; falling back to default compaction window",
@@ -1113,12 +1145,45 @@ async fn run(
         keep_recent_tokens: config.compaction_keep_recent_tokens(),
         context_window_tokens,
     };
+
+    if let Some(batch_request) = batch_request {
+        return run_batch_mode(
+            &batch_request,
+            provider,
+            enabled_tools.iter().map(ToString::to_string).collect(),
+            cwd.clone(),
+            config.clone(),
+            agent_config,
+            compaction_settings,
+        )
+        .await;
+    }
+
+    let mut tools = ToolRegistry::new(&enabled_tools, &cwd, Some(&config));
+    let session_arc = Arc::new(Mutex::new(session));
+    if enabled_tools.contains(&"task") {
+        tools.push(Box::new(pi::tools::TaskTool::new(
+            Arc::clone(&provider),
+            cwd.clone(),
+            Arc::new(config.clone()),
+            Arc::clone(&session_arc),
+            enabled_tools.iter().map(ToString::to_string).collect(),
+        )));
+    }
     let mut agent_session = AgentSession::new(
         Agent::new(provider, tools, agent_config),
         session_arc,
         !cli.no_session,
         compaction_settings,
     );
+    #[cfg(feature = "otel")]
+    if let Some(exporter) = config
+        .otel
+        .as_ref()
+        .and_then(pi::otel::OtelExporter::from_settings)
+    {
+        agent_session = agent_session.with_otel_exporter(std::sync::Arc::new(exporter));
+    }
 
     let history = {
         let cx = pi::agent_cx::AgentCx::for_request();
@@ -1183,6 +1248,14 @@ async fn run(
             "Resolved extension repair policy for runtime"
         );
         maybe_print_extension_policy_migration_notice(&resolved_ext_policy);
+        let workspace_roots = if cli.workspace_root.is_empty() {
+            None
+        } else {
+            Some(
+                pi::workspace::WorkspaceRoots::from_specs(&cli.workspace_root)
+                    .map_err(anyhow::Error::new)?,
+            )
+        };
         agent_session
             .enable_extensions_with_policy(
                 &enabled_tools,
@@ -1192,10 +1265,15 @@ async fn run(
                 Some(resolved_ext_policy.policy),
                 Some(effective_repair_policy),
                 pre_warmed,
+                workspace_roots.as_ref(),
             )
             .await
             .map_err(anyhow::Error::new)?;
 
+        if let Some(region) = &agent_session.extensions {
+            region.manager().set_user_commands(resources.command_values());
+        }
+
         if !extension_flags.is_empty() {
             if let Some(region) = &agent_session.extensions {
                 apply_extension_cli_flags(region.manager(), &extension_flags).await?;
@@ -1277,6 +1355,28 @@ async fn run(
             rpc_scoped_models,
             auth.clone(),
             runtime_handle.clone(),
+            cli.listen.as_deref(),
+            cli.allow_remote_listen,
+        )
+        .await
+    } else if mode == "acp" {
+        let available_models = model_registry.get_available();
+        let acp_scoped_models = selection
+            .scoped_models
+            .iter()
+            .map(|sm| pi::rpc::RpcScopedModel {
+                model: sm.model.clone(),
+                thinking_level: sm.thinking_level,
+            })
+            .collect::<Vec<_>>();
+        run_acp_mode(
+            agent_session,
+            resources,
+            config.clone(),
+            available_models,
+            acp_scoped_models,
+            auth.clone(),
+            runtime_handle.clone(),
         )
         .await
     } else if is_interactive {
@@ -1363,7 +1463,11 @@ async fn handle_subcommand(command: cli::Commands, cwd: &Path) -> Result<()> {
         cli::Commands::List => {
             handle_package_list(&manager).await?;
         }
-        cli::Commands::Config { show, paths, json } => {
+        cli::Commands::Config { show, paths, json, resolved } => {
+            if resolved {
+                handle_config_resolved_fast(&cwd)?;
+                return Ok(());
+            }
             handle_config(&manager, cwd, show, paths, json).await?;
         }
         cli::Commands::Doctor {
@@ -1385,6 +1489,9 @@ async fn handle_subcommand(command: cli::Commands, cwd: &Path) -> Result<()> {
         cli::Commands::Migrate { path, dry_run } => {
             handle_session_migrate(&path, dry_run)?;
         }
+        cli::Commands::Run { .. } => {
+            unreachable!("Commands::Run is intercepted and handled before handle_subcommand")
+        }
     }
 
     Ok(())
@@ -2517,6 +2624,45 @@ fn handle_config_json_fast(cwd: &Path) -> Result<()> {
     Ok(())
 }
 
+fn handle_config_resolved_fast(cwd: &Path) -> Result<()> {
+    let config = Config::load()?;
+    let sources = resolved_config_sources(cwd);
+    let output = serde_json::json!({
+        "sources": sources,
+        "resolved": config,
+    });
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    Ok(())
+}
+
+/// Paths that were consulted (in merge order, lowest to highest precedence)
+/// when resolving the effective configuration.
+fn resolved_config_sources(cwd: &Path) -> Vec<String> {
+    let mut sources = vec![Config::global_dir().join("settings.json").display().to_string()];
+
+    let mut ancestor_tomls = Vec::new();
+    let mut current = cwd.to_path_buf();
+    loop {
+        let path = current.join(Config::project_dir()).join("config.toml");
+        if path.exists() {
+            ancestor_tomls.push(path.display().to_string());
+        }
+        if !current.pop() {
+            break;
+        }
+    }
+    ancestor_tomls.reverse();
+    sources.extend(ancestor_tomls);
+
+    sources.push(
+        cwd.join(Config::project_dir())
+            .join("settings.json")
+            .display()
+            .to_string(),
+    );
+    sources
+}
+
 fn format_settings_summary(config: &Config) -> String {
     let provider = config.default_provider.as_deref().unwrap_or("(default)");
     let model = config.default_model.as_deref().unwrap_or("(default)");
@@ -3678,6 +3824,45 @@ async fn export_session(input_path: &str, output_path: Option<&str>) -> Result<P
     Ok(output_path)
 }
 
+/// Parse the `--listen` flag into a transport to serve the RPC protocol on.
+/// Accepts `tcp://host:port` and `unix:/path/to/socket`; returns `None` (use
+/// stdio) when `listen` is absent.
+///
+/// The RPC socket has no authentication, so a non-loopback TCP address is
+/// rejected unless `allow_remote_listen` opts in -- otherwise anyone who can
+/// reach the bound address gets the full control plane.
+fn parse_rpc_listen_addr(
+    listen: Option<&str>,
+    allow_remote_listen: bool,
+) -> Result<Option<RpcListenTarget>> {
+    let Some(listen) = listen else {
+        return Ok(None);
+    };
+    if let Some(rest) = listen.strip_prefix("tcp://") {
+        let addr: std::net::SocketAddr = rest
+            .parse()
+            .map_err(|err| anyhow::anyhow!("invalid --listen TCP address {rest:?}: {err}"))?;
+        if !addr.ip().is_loopback() && !allow_remote_listen {
+            bail!(
+                "--listen {listen:?} binds a non-loopback address, which exposes the \
+                 unauthenticated JSON-RPC control plane to the network; pass \
+                 --allow-remote-listen to confirm this is intentional"
+            );
+        }
+        Ok(Some(RpcListenTarget::Tcp(addr)))
+    } else if let Some(path) = listen.strip_prefix("unix:") {
+        Ok(Some(RpcListenTarget::Unix(std::path::PathBuf::from(path))))
+    } else {
+        bail!("--listen must start with \"tcp://\" or \"unix:\", got {listen:?}")
+    }
+}
+
+#[derive(Debug)]
+enum RpcListenTarget {
+    Tcp(std::net::SocketAddr),
+    Unix(std::path::PathBuf),
+}
+
 async fn run_rpc_mode(
     session: AgentSession,
     resources: ResourceLoader,
@@ -3686,6 +3871,8 @@ async fn run_rpc_mode(
     scoped_models: Vec<pi::rpc::RpcScopedModel>,
     auth: AuthStorage,
     runtime_handle: RuntimeHandle,
+    listen: Option<&str>,
+    allow_remote_listen: bool,
 ) -> Result<()> {
     use futures::FutureExt;
 
@@ -3696,17 +3883,22 @@ async fn run_rpc_mode(
     }) {
         eprintln!("Warning: Failed to install Ctrl+C handler for RPC mode: {err}");
     }
-    let rpc_task = pi::rpc::run_stdio(
-        session,
-        pi::rpc::RpcOptions {
-            config,
-            resources,
-            available_models,
-            scoped_models,
-            auth,
-            runtime_handle,
-        },
-    )
+    let options = pi::rpc::RpcOptions {
+        config,
+        resources,
+        available_models,
+        scoped_models,
+        auth,
+        runtime_handle,
+    };
+    let target = parse_rpc_listen_addr(listen, allow_remote_listen)?;
+    let rpc_task = async move {
+        match target {
+            None => pi::rpc::run_stdio(session, options).await,
+            Some(RpcListenTarget::Tcp(addr)) => pi::rpc::run_tcp(session, options, addr).await,
+            Some(RpcListenTarget::Unix(path)) => pi::rpc::run_unix(session, options, path).await,
+        }
+    }
     .fuse();
 
     let signal_task = abort_signal.wait().fuse();
@@ -3725,6 +3917,78 @@ async fn run_rpc_mode(
     }
 }
 
+async fn run_acp_mode(
+    session: AgentSession,
+    resources: ResourceLoader,
+    config: Config,
+    available_models: Vec<ModelEntry>,
+    scoped_models: Vec<pi::rpc::RpcScopedModel>,
+    auth: AuthStorage,
+    runtime_handle: RuntimeHandle,
+) -> Result<()> {
+    use futures::FutureExt;
+
+    let (abort_handle, abort_signal) = AbortHandle::new();
+    let abort_listener = abort_handle.clone();
+    if let Err(err) = ctrlc::set_handler(move || {
+        abort_listener.abort();
+    }) {
+        eprintln!("Warning: Failed to install Ctrl+C handler for ACP mode: {err}");
+    }
+    let options = pi::rpc::RpcOptions {
+        config,
+        resources,
+        available_models,
+        scoped_models,
+        auth,
+        runtime_handle,
+    };
+    let acp_task = pi::acp::run_stdio(session, options).fuse();
+    let signal_task = abort_signal.wait().fuse();
+
+    futures::pin_mut!(acp_task, signal_task);
+
+    match futures::future::select(acp_task, signal_task).await {
+        futures::future::Either::Left((result, _)) => match result {
+            Ok(()) => Ok(()),
+            Err(err) => Err(anyhow::Error::new(err)),
+        },
+        futures::future::Either::Right(((), _)) => {
+            // Signal received, return Ok to trigger main_impl's shutdown flush
+            Ok(())
+        }
+    }
+}
+
+async fn run_batch_mode(
+    request: &pi::batch::BatchRequest,
+    provider: Arc<dyn pi::provider::Provider>,
+    enabled_tools: Vec<String>,
+    cwd: PathBuf,
+    config: Config,
+    agent_config: AgentConfig,
+    compaction_settings: ResolvedCompactionSettings,
+) -> Result<()> {
+    let factory = pi::batch::BatchSessionFactory {
+        provider,
+        enabled_tools,
+        cwd,
+        config: Arc::new(config),
+        agent_config,
+        compaction_settings,
+    };
+
+    let summary = pi::batch::run_batch(request, factory).await?;
+    println!(
+        "Batch run complete: {} succeeded, {} failed, {} skipped (total {})",
+        summary.succeeded, summary.failed, summary.skipped, summary.total
+    );
+    if summary.failed > 0 {
+        bail!("{} prompt(s) failed", summary.failed);
+    }
+    Ok(())
+}
+
 #[allow(clippy::too_many_lines, clippy::too_many_arguments)]
 async fn run_print_mode(
     session: &mut AgentSession,
@@ -4118,6 +4382,7 @@ async fn run_interactive_mode(
         agent,
         session,
         extensions: region,
+        extension_degradation,
         ..
     } = session;
     // Extract manager for the interactive loop; the region stays alive to
@@ -4137,6 +4402,7 @@ async fn run_interactive_mode(
         extensions,
         cwd,
         runtime_handle,
+        extension_degradation,
     )
     .await;
     // Explicitly shut down extension runtimes so the QuickJS GC can
@@ -4252,6 +4518,49 @@ mod tests {
         assert_eq!(exit_code_for_error(&runtime_err), EXIT_CODE_FAILURE);
     }
 
+    #[test]
+    fn parse_rpc_listen_addr_accepts_tcp_and_unix() {
+        assert!(
+            parse_rpc_listen_addr(None, false)
+                .expect("no listen arg")
+                .is_none()
+        );
+
+        match parse_rpc_listen_addr(Some("tcp://127.0.0.1:4317"), false).expect("valid tcp addr")
+        {
+            Some(RpcListenTarget::Tcp(addr)) => assert_eq!(addr.port(), 4317),
+            other => panic!("expected Tcp target, got {other:?}"),
+        }
+
+        match parse_rpc_listen_addr(Some("unix:/tmp/pi.sock"), false).expect("valid unix path") {
+            Some(RpcListenTarget::Unix(path)) => {
+                assert_eq!(path, std::path::PathBuf::from("/tmp/pi.sock"));
+            }
+            other => panic!("expected Unix target, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_rpc_listen_addr_rejects_unknown_scheme_and_bad_port() {
+        let err = parse_rpc_listen_addr(Some("127.0.0.1:4317"), false).expect_err("missing scheme");
+        assert!(err.to_string().contains("tcp://"));
+
+        let err = parse_rpc_listen_addr(Some("tcp://not-an-addr"), false).expect_err("bad tcp addr");
+        assert!(err.to_string().contains("invalid --listen"));
+    }
+
+    #[test]
+    fn parse_rpc_listen_addr_rejects_non_loopback_without_opt_in() {
+        let err = parse_rpc_listen_addr(Some("tcp://0.0.0.0:4317"), false)
+            .expect_err("non-loopback address without opt-in");
+        assert!(err.to_string().contains("--allow-remote-listen"));
+
+        match parse_rpc_listen_addr(Some("tcp://0.0.0.0:4317"), true).expect("opted in") {
+            Some(RpcListenTarget::Tcp(addr)) => assert_eq!(addr.port(), 4317),
+            other => panic!("expected Tcp target, got {other:?}"),
+        }
+    }
+
     #[test]
     fn parse_cli_args_extracts_extension_flags() {
         let parsed = parse_cli_args(vec![
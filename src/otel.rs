@@ -0,0 +1,347 @@
+//! OpenTelemetry (OTLP/HTTP) export of spans and metrics.
+//!
+//! This is a small, purpose-built exporter rather than a wrapper around the
+//! upstream `opentelemetry` crate family: it reuses [`crate::http::client::Client`]
+//! for transport and emits the OTLP JSON wire format directly, so there is no
+//! extra gRPC/protobuf dependency pulled in behind the `otel` feature.
+//!
+//! [`SpanKind`] has variants for agent turns, provider requests, tool
+//! executions, and hostcalls, but the agent records only one `agent.turn` span
+//! per turn started through `AgentSession::run_text_with_abort`, under a freshly
+//! generated trace id. No provider, tool or hostcall spans and no metrics are
+//! recorded by the agent, and span ids are not taken from
+//! [`crate::extensions::LogCorrelation`].
+//!
+//! Disabled unless `[otel] enabled = true` is set in the config file (or
+//! [`OtelExporter::new`] is constructed directly by an embedder).
+
+use crate::config::OtelSettings;
+use crate::error::Result;
+use crate::http::client::Client;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+/// What kind of unit of work a [`Span`] represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanKind {
+    AgentTurn,
+    ProviderRequest,
+    ToolExecution,
+    Hostcall,
+}
+
+impl SpanKind {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::AgentTurn => "agent.turn",
+            Self::ProviderRequest => "provider.request",
+            Self::ToolExecution => "tool.execution",
+            Self::Hostcall => "hostcall",
+        }
+    }
+}
+
+/// A single completed span, ready to export.
+#[derive(Debug, Clone)]
+pub struct Span {
+    pub name: String,
+    pub kind: SpanKind,
+    pub trace_id: String,
+    pub span_id: String,
+    pub parent_span_id: Option<String>,
+    pub start_unix_nanos: u128,
+    pub end_unix_nanos: u128,
+    pub attributes: HashMap<String, String>,
+}
+
+/// Generate a fresh OTLP-shaped trace id (32 hex chars / 16 bytes).
+#[must_use]
+pub fn new_trace_id() -> String {
+    Uuid::new_v4().simple().to_string()
+}
+
+/// Generate a fresh OTLP-shaped span id (16 hex chars / 8 bytes).
+#[must_use]
+pub fn new_span_id() -> String {
+    Uuid::new_v4().simple().to_string()[..16].to_string()
+}
+
+/// Builder for a single span; call [`SpanBuilder::finish`] to record its end time.
+pub struct SpanBuilder {
+    name: String,
+    kind: SpanKind,
+    trace_id: String,
+    span_id: String,
+    parent_span_id: Option<String>,
+    start_unix_nanos: u128,
+    attributes: HashMap<String, String>,
+}
+
+impl SpanBuilder {
+    #[must_use]
+    pub fn start(name: impl Into<String>, kind: SpanKind, trace_id: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            trace_id: trace_id.into(),
+            span_id: new_span_id(),
+            parent_span_id: None,
+            start_unix_nanos: unix_nanos_now(),
+            attributes: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_parent(mut self, parent_span_id: impl Into<String>) -> Self {
+        self.parent_span_id = Some(parent_span_id.into());
+        self
+    }
+
+    #[must_use]
+    pub fn attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.insert(key.into(), value.into());
+        self
+    }
+
+    #[must_use]
+    pub fn span_id(&self) -> &str {
+        &self.span_id
+    }
+
+    #[must_use]
+    pub fn finish(self) -> Span {
+        Span {
+            name: self.name,
+            kind: self.kind,
+            trace_id: self.trace_id,
+            span_id: self.span_id,
+            parent_span_id: self.parent_span_id,
+            start_unix_nanos: self.start_unix_nanos,
+            end_unix_nanos: unix_nanos_now(),
+            attributes: self.attributes,
+        }
+    }
+}
+
+/// A metric sample: either a monotonic counter increment or a histogram observation
+/// (latency in ms, tokens, or cost in USD, depending on `name`).
+#[derive(Debug, Clone)]
+pub struct MetricPoint {
+    pub name: String,
+    pub value: f64,
+    pub attributes: HashMap<String, String>,
+    pub unix_nanos: u128,
+}
+
+fn unix_nanos_now() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+}
+
+/// Buffers spans/metrics and flushes them to the configured OTLP/HTTP collector.
+pub struct OtelExporter {
+    client: Client,
+    endpoint: String,
+    headers: Vec<(String, String)>,
+    service_name: String,
+    spans: Mutex<Vec<Span>>,
+    metrics: Mutex<Vec<MetricPoint>>,
+}
+
+impl OtelExporter {
+    /// Build an exporter from config, or `None` if otel export is disabled/unconfigured.
+    #[must_use]
+    pub fn from_settings(settings: &OtelSettings) -> Option<Self> {
+        if !settings.enabled.unwrap_or(false) {
+            return None;
+        }
+        let endpoint = settings.endpoint.clone()?;
+        Some(Self {
+            client: Client::new(),
+            endpoint,
+            headers: settings
+                .headers
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+            service_name: settings
+                .service_name
+                .clone()
+                .unwrap_or_else(|| "pi_agent_rust".to_string()),
+            spans: Mutex::new(Vec::new()),
+            metrics: Mutex::new(Vec::new()),
+        })
+    }
+
+    pub fn record_span(&self, span: Span) {
+        self.spans.lock().unwrap().push(span);
+    }
+
+    pub fn record_counter(
+        &self,
+        name: impl Into<String>,
+        value: f64,
+        attributes: HashMap<String, String>,
+    ) {
+        self.metrics.lock().unwrap().push(MetricPoint {
+            name: name.into(),
+            value,
+            attributes,
+            unix_nanos: unix_nanos_now(),
+        });
+    }
+
+    pub fn record_histogram(
+        &self,
+        name: impl Into<String>,
+        value: f64,
+        attributes: HashMap<String, String>,
+    ) {
+        self.record_counter(name, value, attributes);
+    }
+
+    /// Drain buffered spans/metrics and POST them to the configured OTLP/HTTP endpoint.
+    /// No-op (and leaves the buffers empty) if nothing has been recorded since the last flush.
+    pub async fn flush(&self) -> Result<()> {
+        let spans = std::mem::take(&mut *self.spans.lock().unwrap());
+        let metrics = std::mem::take(&mut *self.metrics.lock().unwrap());
+        if spans.is_empty() && metrics.is_empty() {
+            return Ok(());
+        }
+
+        let payload = self.to_otlp_json(&spans, &metrics);
+        let mut request = self.client.post(&self.endpoint);
+        for (key, value) in &self.headers {
+            request = request.header(key.clone(), value.clone());
+        }
+        request.json(&payload)?.send().await?;
+        Ok(())
+    }
+
+    /// Render buffered spans/metrics as an OTLP-JSON-shaped payload (simplified: flat
+    /// resource/scope, no protobuf-specific field packing).
+    fn to_otlp_json(&self, spans: &[Span], metrics: &[MetricPoint]) -> Value {
+        let resource_attributes = json!([
+            {"key": "service.name", "value": {"stringValue": self.service_name}},
+        ]);
+
+        let otlp_spans: Vec<Value> = spans
+            .iter()
+            .map(|span| {
+                json!({
+                    "name": span.name,
+                    "kind": span.kind.as_str(),
+                    "traceId": span.trace_id,
+                    "spanId": span.span_id,
+                    "parentSpanId": span.parent_span_id,
+                    "startTimeUnixNano": span.start_unix_nanos.to_string(),
+                    "endTimeUnixNano": span.end_unix_nanos.to_string(),
+                    "attributes": span
+                        .attributes
+                        .iter()
+                        .map(|(k, v)| json!({"key": k, "value": {"stringValue": v}}))
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+
+        let otlp_metrics: Vec<Value> = metrics
+            .iter()
+            .map(|metric| {
+                json!({
+                    "name": metric.name,
+                    "timeUnixNano": metric.unix_nanos.to_string(),
+                    "asDouble": metric.value,
+                    "attributes": metric
+                        .attributes
+                        .iter()
+                        .map(|(k, v)| json!({"key": k, "value": {"stringValue": v}}))
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+
+        json!({
+            "resourceSpans": [{
+                "resource": {"attributes": resource_attributes},
+                "scopeSpans": [{"spans": otlp_spans}],
+            }],
+            "resourceMetrics": [{
+                "resource": {"attributes": resource_attributes},
+                "scopeMetrics": [{"metrics": otlp_metrics}],
+            }],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(endpoint: &str) -> OtelSettings {
+        OtelSettings {
+            enabled: Some(true),
+            endpoint: Some(endpoint.to_string()),
+            headers: None,
+            service_name: Some("pi-test".to_string()),
+        }
+    }
+
+    #[test]
+    fn disabled_settings_produce_no_exporter() {
+        let mut disabled = settings("https://collector.example.test/v1/traces");
+        disabled.enabled = Some(false);
+        assert!(OtelExporter::from_settings(&disabled).is_none());
+    }
+
+    #[test]
+    fn missing_endpoint_produces_no_exporter() {
+        let mut missing_endpoint = settings("unused");
+        missing_endpoint.endpoint = None;
+        assert!(OtelExporter::from_settings(&missing_endpoint).is_none());
+    }
+
+    #[test]
+    fn span_builder_round_trips_attributes_and_parent() {
+        let trace_id = new_trace_id();
+        let span = SpanBuilder::start("agent.turn", SpanKind::AgentTurn, trace_id.clone())
+            .attribute("session_id", "sess-1")
+            .with_parent("parent-span")
+            .finish();
+
+        assert_eq!(span.trace_id, trace_id);
+        assert_eq!(span.parent_span_id.as_deref(), Some("parent-span"));
+        assert_eq!(span.attributes.get("session_id").map(String::as_str), Some("sess-1"));
+        assert!(span.end_unix_nanos >= span.start_unix_nanos);
+    }
+
+    #[test]
+    fn exporter_buffers_until_flush() {
+        let exporter = OtelExporter::from_settings(&settings("https://collector.example.test/v1/traces"))
+            .expect("exporter");
+        let trace_id = new_trace_id();
+        exporter.record_span(
+            SpanBuilder::start("tool.execution", SpanKind::ToolExecution, trace_id).finish(),
+        );
+        exporter.record_counter("pi.tokens.input", 42.0, HashMap::new());
+
+        assert_eq!(exporter.spans.lock().unwrap().len(), 1);
+        assert_eq!(exporter.metrics.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn trace_and_span_ids_are_otlp_shaped_hex() {
+        let trace_id = new_trace_id();
+        let span_id = new_span_id();
+        assert_eq!(trace_id.len(), 32);
+        assert!(trace_id.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(span_id.len(), 16);
+        assert!(span_id.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+}
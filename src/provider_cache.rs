@@ -0,0 +1,244 @@
+//! Opt-in on-disk response cache for deterministic provider calls.
+//!
+//! Keyed on `(model, messages, params)`, this lets repeated test/e2e runs
+//! and batch jobs skip the network round-trip entirely when replaying an
+//! identical request. Disabled by default; callers opt in explicitly and
+//! can always bypass it with `--no-cache` (see [`crate::cli::Cli::no_cache`]).
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default time a cached response stays valid.
+const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+/// Default cap on total cache size before oldest entries are evicted.
+const DEFAULT_MAX_SIZE_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Cache hit/miss counters, surfaced alongside token usage stats.
+#[derive(Debug, Default)]
+pub struct CacheStats {
+    pub hits: AtomicU64,
+    pub misses: AtomicU64,
+}
+
+impl CacheStats {
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    stored_at_secs: u64,
+    body: String,
+}
+
+/// On-disk cache of provider completions, keyed by model + request hash.
+#[derive(Debug)]
+pub struct ProviderCache {
+    dir: PathBuf,
+    ttl: Duration,
+    max_size_bytes: u64,
+    stats: CacheStats,
+}
+
+impl ProviderCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            ttl: DEFAULT_TTL,
+            max_size_bytes: DEFAULT_MAX_SIZE_BYTES,
+            stats: CacheStats::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_size_bytes(mut self, max_size_bytes: u64) -> Self {
+        self.max_size_bytes = max_size_bytes;
+        self
+    }
+
+    pub const fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
+
+    /// Derive a cache key from the model id, the serialized message history,
+    /// and any request parameters that affect the completion.
+    pub fn key(model: &str, messages_json: &str, params_json: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(model.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(messages_json.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(params_json.as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+
+    /// Look up a cached response body. Missing, unreadable and expired
+    /// entries count as misses; expired entries are removed lazily.
+    pub fn get(&self, key: &str) -> Option<String> {
+        let path = self.path_for(key);
+        let entry = fs::read_to_string(&path)
+            .ok()
+            .and_then(|raw| serde_json::from_str::<CacheEntry>(&raw).ok());
+        let Some(entry) = entry else {
+            self.stats.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        if now.saturating_sub(entry.stored_at_secs) >= self.ttl.as_secs() {
+            let _ = fs::remove_file(&path);
+            self.stats.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+        self.stats.hits.fetch_add(1, Ordering::Relaxed);
+        Some(entry.body)
+    }
+
+    /// Store a response body under `key`, evicting the oldest entries first
+    /// if the cache would exceed `max_size_bytes`.
+    pub fn put(&self, key: &str, body: &str) -> Result<()> {
+        fs::create_dir_all(&self.dir)
+            .map_err(|err| Error::validation(format!("provider cache dir: {err}")))?;
+
+        let entry = CacheEntry {
+            stored_at_secs: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            body: body.to_string(),
+        };
+        let serialized = serde_json::to_string(&entry)
+            .map_err(|err| Error::validation(format!("provider cache entry: {err}")))?;
+
+        self.evict_to_fit(serialized.len() as u64);
+
+        fs::write(self.path_for(key), serialized)
+            .map_err(|err| Error::validation(format!("provider cache write: {err}")))
+    }
+
+    /// Count a miss for a body that `get` returned but the caller could not
+    /// use (e.g. it failed to decode); `get` already counts its own misses.
+    pub fn record_miss(&self) {
+        self.stats.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn evict_to_fit(&self, incoming_bytes: u64) {
+        let Ok(read_dir) = fs::read_dir(&self.dir) else {
+            return;
+        };
+
+        let mut entries: Vec<(PathBuf, u64, std::time::SystemTime)> = read_dir
+            .filter_map(std::result::Result::ok)
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                let modified = metadata.modified().ok()?;
+                Some((entry.path(), metadata.len(), modified))
+            })
+            .collect();
+
+        let mut total: u64 = entries.iter().map(|(_, size, _)| size).sum::<u64>() + incoming_bytes;
+        if total <= self.max_size_bytes {
+            return;
+        }
+
+        entries.sort_by_key(|(_, _, modified)| *modified);
+        for (path, size, _) in entries {
+            if total <= self.max_size_bytes {
+                break;
+            }
+            if fs::remove_file(&path).is_ok() {
+                total = total.saturating_sub(size);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("pi_provider_cache_test_{name}_{}", std::process::id()))
+    }
+
+    #[test]
+    fn key_is_stable_and_sensitive_to_input() {
+        let a = ProviderCache::key("claude-opus-4", "[]", "{}");
+        let b = ProviderCache::key("claude-opus-4", "[]", "{}");
+        let c = ProviderCache::key("claude-opus-4", "[\"hi\"]", "{}");
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn put_then_get_round_trips() {
+        let dir = temp_cache_dir("roundtrip");
+        let cache = ProviderCache::new(dir.clone());
+        let key = ProviderCache::key("m", "[]", "{}");
+
+        assert!(cache.get(&key).is_none());
+        assert_eq!(cache.stats().misses(), 1);
+
+        cache.put(&key, "response body").expect("put");
+        assert_eq!(cache.get(&key).as_deref(), Some("response body"));
+        assert_eq!(cache.stats().hits(), 1);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn expired_entry_is_a_miss() {
+        let dir = temp_cache_dir("expired");
+        let cache = ProviderCache::new(dir.clone()).with_ttl(Duration::from_secs(0));
+        let key = ProviderCache::key("m", "[]", "{}");
+        cache.put(&key, "stale").expect("put");
+
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(cache.get(&key).is_none());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn eviction_keeps_cache_under_max_size() {
+        let dir = temp_cache_dir("eviction");
+        let cache = ProviderCache::new(dir.clone()).with_max_size_bytes(64);
+
+        for i in 0..10 {
+            let key = ProviderCache::key("m", &format!("[{i}]"), "{}");
+            cache.put(&key, "x".repeat(20).as_str()).expect("put");
+        }
+
+        let total: u64 = fs::read_dir(&dir)
+            .expect("read dir")
+            .filter_map(std::result::Result::ok)
+            .filter_map(|e| e.metadata().ok())
+            .map(|m| m.len())
+            .sum();
+        assert!(total <= 64 + 200, "cache did not evict, total={total}");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -830,6 +830,237 @@ impl Provider for ExtensionStreamSimpleProvider {
     }
 }
 
+/// Wraps a [`Provider`] with a [`RateLimiter`](crate::rate_limiter::RateLimiter) permit acquired
+/// before each `stream()` call and held for the lifetime of the returned stream, so the
+/// concurrency cap reflects in-flight requests rather than just request starts.
+struct RateLimitedProvider {
+    inner: Arc<dyn Provider>,
+    limiter: Arc<crate::rate_limiter::RateLimiter>,
+    key: String,
+}
+
+/// How long `stream()` queues behind a saturated rate limit before giving up.
+const RATE_LIMIT_MAX_WAIT: std::time::Duration = std::time::Duration::from_secs(30);
+
+#[async_trait]
+impl Provider for RateLimitedProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn api(&self) -> &str {
+        self.inner.api()
+    }
+
+    fn model_id(&self) -> &str {
+        self.inner.model_id()
+    }
+
+    async fn stream(
+        &self,
+        context: &Context<'_>,
+        options: &StreamOptions,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamEvent>> + Send>>> {
+        let permit = self
+            .limiter
+            .acquire(&self.key, 0, RATE_LIMIT_MAX_WAIT)
+            .await?;
+        let inner_stream = self.inner.stream(context, options).await?;
+        Ok(Box::pin(stream::unfold(
+            (inner_stream, permit),
+            |(mut inner_stream, permit)| async move {
+                use futures::StreamExt;
+                let item = inner_stream.next().await?;
+                Some((item, (inner_stream, permit)))
+            },
+        )))
+    }
+}
+
+/// Lazily-initialized process-wide rate limiter, built from `Config.rate_limits` the first time
+/// [`apply_rate_limit`] is called with limits enabled. Shared across providers/models so
+/// limits set on a provider-wide key apply regardless of which model within it is in use.
+static RATE_LIMITER: std::sync::OnceLock<Arc<crate::rate_limiter::RateLimiter>> =
+    std::sync::OnceLock::new();
+
+fn rate_limiter_for(settings: &crate::config::RateLimitSettings) -> Arc<crate::rate_limiter::RateLimiter> {
+    RATE_LIMITER
+        .get_or_init(|| {
+            let default_config = crate::rate_limiter::RateLimitConfig {
+                requests_per_minute: settings.requests_per_minute,
+                tokens_per_minute: settings.tokens_per_minute,
+                max_concurrent: settings.max_concurrent,
+            };
+            let limiter = crate::rate_limiter::RateLimiter::new(default_config);
+            for (key, override_config) in settings.overrides.iter().flatten() {
+                limiter.set_config(
+                    key.clone(),
+                    crate::rate_limiter::RateLimitConfig {
+                        requests_per_minute: override_config
+                            .requests_per_minute
+                            .or(settings.requests_per_minute),
+                        tokens_per_minute: override_config
+                            .tokens_per_minute
+                            .or(settings.tokens_per_minute),
+                        max_concurrent: override_config.max_concurrent.or(settings.max_concurrent),
+                    },
+                );
+            }
+            Arc::new(limiter)
+        })
+        .clone()
+}
+
+/// Wrap `provider` with rate limiting if `Config.rate_limits.enabled` is set.
+pub fn apply_rate_limit(
+    provider: Arc<dyn Provider>,
+    entry: &ModelEntry,
+    rate_limits: Option<&crate::config::RateLimitSettings>,
+) -> Arc<dyn Provider> {
+    let Some(settings) = rate_limits else {
+        return provider;
+    };
+    if !settings.enabled.unwrap_or(false) {
+        return provider;
+    }
+    let limiter = rate_limiter_for(settings);
+    let model_key = format!("{}/{}", entry.model.provider, entry.model.id);
+    // Fall back to the provider-wide key when only the provider has an override, so its
+    // limits (and counters) are shared by every model of that provider.
+    let provider_only = settings.overrides.as_ref().is_some_and(|overrides| {
+        !overrides.contains_key(&model_key) && overrides.contains_key(&entry.model.provider)
+    });
+    let key = if provider_only {
+        entry.model.provider.clone()
+    } else {
+        model_key
+    };
+    Arc::new(RateLimitedProvider {
+        inner: provider,
+        limiter,
+        key,
+    })
+}
+
+/// Wraps a [`Provider`] with an opt-in on-disk response cache: identical `(model, messages,
+/// params)` requests replay the cached completion instead of hitting the network.
+struct CachedProvider {
+    inner: Arc<dyn Provider>,
+    cache: Arc<crate::provider_cache::ProviderCache>,
+}
+
+impl CachedProvider {
+    fn cache_key(&self, context: &Context<'_>, options: &StreamOptions) -> String {
+        let messages_json = serde_json::to_string(&context.messages).unwrap_or_default();
+        let params_json = serde_json::json!({
+            "temperature": options.temperature,
+            "maxTokens": options.max_tokens,
+            "thinkingLevel": options.thinking_level,
+        })
+        .to_string();
+        crate::provider_cache::ProviderCache::key(self.inner.model_id(), &messages_json, &params_json)
+    }
+}
+
+#[async_trait]
+impl Provider for CachedProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn api(&self) -> &str {
+        self.inner.api()
+    }
+
+    fn model_id(&self) -> &str {
+        self.inner.model_id()
+    }
+
+    async fn stream(
+        &self,
+        context: &Context<'_>,
+        options: &StreamOptions,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamEvent>> + Send>>> {
+        let key = self.cache_key(context, options);
+
+        if let Some(cached_json) = self.cache.get(&key) {
+            if let Ok(message) = serde_json::from_str::<AssistantMessage>(&cached_json) {
+                let events = vec![
+                    Ok(StreamEvent::Start {
+                        partial: message.clone(),
+                    }),
+                    Ok(StreamEvent::Done {
+                        reason: message.stop_reason,
+                        message,
+                    }),
+                ];
+                return Ok(Box::pin(stream::iter(events)));
+            }
+            self.cache.record_miss();
+        }
+
+        let inner_stream = self.inner.stream(context, options).await?;
+        let cache = self.cache.clone();
+        Ok(Box::pin(stream::unfold(
+            (inner_stream, cache, key),
+            |(mut inner_stream, cache, key)| async move {
+                use futures::StreamExt;
+                let item = inner_stream.next().await?;
+                if let Ok(StreamEvent::Done { message, .. }) = &item {
+                    if let Ok(body) = serde_json::to_string(message) {
+                        let _ = cache.put(&key, &body);
+                    }
+                }
+                Some((item, (inner_stream, cache, key)))
+            },
+        )))
+    }
+}
+
+/// Lazily-initialized process-wide provider cache, built from `Config.provider_cache` the first
+/// time [`apply_provider_cache`] is called with the cache enabled.
+static PROVIDER_CACHE: std::sync::OnceLock<Arc<crate::provider_cache::ProviderCache>> =
+    std::sync::OnceLock::new();
+
+/// Wrap `provider` with the on-disk response cache if `Config.provider_cache.enabled` is set and
+/// `--no-cache` was not passed.
+pub fn apply_provider_cache(
+    provider: Arc<dyn Provider>,
+    provider_cache: Option<&crate::config::ProviderCacheSettings>,
+    no_cache: bool,
+    default_cache_dir: &std::path::Path,
+) -> Arc<dyn Provider> {
+    if no_cache {
+        return provider;
+    }
+    let Some(settings) = provider_cache else {
+        return provider;
+    };
+    if !settings.enabled.unwrap_or(false) {
+        return provider;
+    }
+    let cache = PROVIDER_CACHE
+        .get_or_init(|| {
+            let dir = settings
+                .dir
+                .as_ref()
+                .map_or_else(|| default_cache_dir.to_path_buf(), std::path::PathBuf::from);
+            let mut cache = crate::provider_cache::ProviderCache::new(dir);
+            if let Some(ttl_secs) = settings.ttl_secs {
+                cache = cache.with_ttl(std::time::Duration::from_secs(ttl_secs));
+            }
+            if let Some(max_size_bytes) = settings.max_size_bytes {
+                cache = cache.with_max_size_bytes(max_size_bytes);
+            }
+            Arc::new(cache)
+        })
+        .clone();
+    Arc::new(CachedProvider {
+        inner: provider,
+        cache,
+    })
+}
+
 #[allow(clippy::too_many_lines)]
 pub fn create_provider(
     entry: &ModelEntry,
@@ -0,0 +1,179 @@
+//! Reusable library API for evaluating release-readiness gate artifacts.
+//!
+//! The `must_pass` gate checks were originally written only inside
+//! `tests/release_readiness.rs`. This module holds the verdict parsing and
+//! metadata validation for `pi.ext.must_pass_gate.v1` artifacts so external
+//! tooling can evaluate a gate without compiling the test crate. The parameter
+//! sweeps and opportunity matrix schemas are exposed only as schema-name
+//! constants, with no validators, and no `pi` subcommand calls this module.
+
+use serde_json::Value;
+
+pub const MUST_PASS_GATE_SCHEMA: &str = "pi.ext.must_pass_gate.v1";
+pub const PARAMETER_SWEEPS_SCHEMA: &str = "pi.perf.parameter_sweeps.v1";
+pub const OPPORTUNITY_MATRIX_SCHEMA: &str = "pi.perf.opportunity_matrix.v1";
+
+/// Overall verdict for a single gate evaluation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateSignal {
+    Pass,
+    Warn,
+    Fail,
+    NoData,
+}
+
+impl std::fmt::Display for GateSignal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => f.write_str("PASS"),
+            Self::Warn => f.write_str("WARN"),
+            Self::Fail => f.write_str("FAIL"),
+            Self::NoData => f.write_str("NO_DATA"),
+        }
+    }
+}
+
+/// Result of evaluating a `pi.ext.must_pass_gate.v1` artifact.
+#[derive(Debug, Clone)]
+pub struct MustPassGateOutcome {
+    pub signal: GateSignal,
+    pub status: String,
+    pub passed: u64,
+    pub total: u64,
+    pub metadata_errors: Vec<String>,
+}
+
+fn get_u64(v: &Value, pointer: &str) -> u64 {
+    v.pointer(pointer).and_then(Value::as_u64).unwrap_or(0)
+}
+
+fn get_str<'a>(v: &'a Value, pointer: &str) -> &'a str {
+    v.pointer(pointer).and_then(Value::as_str).unwrap_or("unknown")
+}
+
+/// Parse `(status, passed, total)` out of a must_pass gate artifact,
+/// falling back from the current schema's `/observed/...` fields to the
+/// legacy top-level `/passed` and `/total` fields.
+pub fn parse_must_pass_gate_verdict(v: &Value) -> (String, u64, u64) {
+    let status = match get_str(v, "/status") {
+        "unknown" => get_str(v, "/verdict").to_string(),
+        value => value.to_string(),
+    };
+
+    let total = match get_u64(v, "/observed/must_pass_total") {
+        0 => get_u64(v, "/total"),
+        value => value,
+    };
+    let passed = match get_u64(v, "/observed/must_pass_passed") {
+        0 => get_u64(v, "/passed"),
+        value => value,
+    };
+
+    (status, passed, total)
+}
+
+/// Validate the required envelope fields of a must_pass gate artifact.
+/// Returns one human-readable error per missing/invalid field.
+pub fn validate_must_pass_gate_metadata(v: &Value) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    let schema = get_str(v, "/schema");
+    if schema != MUST_PASS_GATE_SCHEMA {
+        errors.push(format!(
+            "schema must be {MUST_PASS_GATE_SCHEMA}, found {schema}"
+        ));
+    }
+
+    for field in ["/generated_at", "/run_id", "/correlation_id"] {
+        if get_str(v, field) == "unknown" {
+            errors.push(format!("missing required field: {field}"));
+        }
+    }
+
+    if v.pointer("/observed").is_none() {
+        errors.push("missing required object: /observed".to_string());
+    }
+
+    errors
+}
+
+/// Fully evaluate a must_pass gate artifact: validate its metadata, then
+/// derive an overall [`GateSignal`] from the reported status and counts.
+pub fn evaluate_must_pass_gate(v: &Value) -> MustPassGateOutcome {
+    let metadata_errors = validate_must_pass_gate_metadata(v);
+    let (status, passed, total) = parse_must_pass_gate_verdict(v);
+
+    let signal = if !metadata_errors.is_empty() {
+        GateSignal::Fail
+    } else if total == 0 {
+        GateSignal::NoData
+    } else if passed >= total && status.eq_ignore_ascii_case("pass") {
+        GateSignal::Pass
+    } else {
+        GateSignal::Fail
+    };
+
+    MustPassGateOutcome {
+        signal,
+        status,
+        passed,
+        total,
+        metadata_errors,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn evaluates_current_schema_as_pass() {
+        let gate = json!({
+            "schema": MUST_PASS_GATE_SCHEMA,
+            "status": "pass",
+            "generated_at": "2026-01-01T00:00:00Z",
+            "run_id": "run-1",
+            "correlation_id": "corr-1",
+            "observed": { "must_pass_total": 208, "must_pass_passed": 208 },
+        });
+
+        let outcome = evaluate_must_pass_gate(&gate);
+        assert_eq!(outcome.signal, GateSignal::Pass);
+        assert_eq!(outcome.passed, 208);
+        assert_eq!(outcome.total, 208);
+        assert!(outcome.metadata_errors.is_empty());
+    }
+
+    #[test]
+    fn evaluates_partial_pass_count_as_fail() {
+        let gate = json!({
+            "schema": MUST_PASS_GATE_SCHEMA,
+            "status": "pass",
+            "generated_at": "2026-01-01T00:00:00Z",
+            "run_id": "run-1",
+            "correlation_id": "corr-1",
+            "observed": { "must_pass_total": 208, "must_pass_passed": 200 },
+        });
+
+        let outcome = evaluate_must_pass_gate(&gate);
+        assert_eq!(outcome.signal, GateSignal::Fail);
+    }
+
+    #[test]
+    fn missing_metadata_fields_fail_closed() {
+        let gate = json!({ "schema": MUST_PASS_GATE_SCHEMA });
+        let outcome = evaluate_must_pass_gate(&gate);
+        assert_eq!(outcome.signal, GateSignal::Fail);
+        assert!(!outcome.metadata_errors.is_empty());
+    }
+
+    #[test]
+    fn legacy_schema_falls_back_to_top_level_fields() {
+        let gate = json!({ "passed": 10, "total": 10, "verdict": "pass" });
+        let (status, passed, total) = parse_must_pass_gate_verdict(&gate);
+        assert_eq!(status, "pass");
+        assert_eq!(passed, 10);
+        assert_eq!(total, 10);
+    }
+}
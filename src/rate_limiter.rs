@@ -0,0 +1,288 @@
+//! Provider-aware rate limiting for outbound completion requests.
+//!
+//! Multi-agent and batch workloads can issue many concurrent completions against the same
+//! provider, which is the fastest way to trip a 429 storm. [`RateLimiter`] tracks requests/min,
+//! tokens/min, and max-concurrent-stream caps per caller-chosen key (typically
+//! `"{provider}/{model}"`, falling back to a provider-wide key), so callers can either shed a
+//! request immediately ([`RateLimiter::try_acquire`]) or queue behind the limit for a bounded
+//! time ([`RateLimiter::acquire`]).
+
+use crate::error::{Error, Result};
+use asupersync::time::{sleep, wall_now};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Width of the sliding window used for requests/min and tokens/min accounting.
+const WINDOW: Duration = Duration::from_secs(60);
+/// How long `acquire` sleeps between retries while queued behind a saturated limit.
+const QUEUE_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Rate-limit thresholds for a single provider or model entry. `None` means "no limit".
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RateLimitConfig {
+    pub requests_per_minute: Option<u32>,
+    pub tokens_per_minute: Option<u32>,
+    pub max_concurrent: Option<u32>,
+}
+
+impl RateLimitConfig {
+    /// A config with every cap disabled.
+    #[must_use]
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Default)]
+struct KeyState {
+    window_start_secs: AtomicU64,
+    request_count: AtomicU32,
+    token_count: AtomicU64,
+    in_flight: AtomicU32,
+}
+
+impl KeyState {
+    /// Reset the request/token counters if the current minute window has elapsed.
+    fn roll_window_if_expired(&self, now_secs: u64) {
+        let start = self.window_start_secs.load(Ordering::SeqCst);
+        if now_secs.saturating_sub(start) < WINDOW.as_secs() {
+            return;
+        }
+        // Only the thread that wins the race resets counts; losers just proceed with whatever
+        // the winner left behind, which is still within the new window.
+        if self
+            .window_start_secs
+            .compare_exchange(start, now_secs, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+        {
+            self.request_count.store(0, Ordering::SeqCst);
+            self.token_count.store(0, Ordering::SeqCst);
+        }
+    }
+}
+
+/// A reserved slot against a [`RateLimiter`] key. Releases its concurrency slot on drop.
+#[derive(Debug)]
+pub struct RateLimitPermit {
+    state: Arc<KeyState>,
+}
+
+impl Drop for RateLimitPermit {
+    fn drop(&mut self) {
+        self.state.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Shared limiter enforcing per-provider/per-model request, token, and concurrency caps.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    configs: Mutex<HashMap<String, RateLimitConfig>>,
+    default_config: RateLimitConfig,
+    states: Mutex<HashMap<String, Arc<KeyState>>>,
+}
+
+impl RateLimiter {
+    pub fn new(default_config: RateLimitConfig) -> Self {
+        Self {
+            configs: Mutex::new(HashMap::new()),
+            default_config,
+            states: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Configure limits for a specific key (e.g. `"anthropic"` or `"anthropic/claude-opus-4"`),
+    /// overriding `default_config` for that key only.
+    pub fn set_config(&self, key: impl Into<String>, config: RateLimitConfig) {
+        self.configs.lock().unwrap().insert(key.into(), config);
+    }
+
+    fn config_for(&self, key: &str) -> RateLimitConfig {
+        self.configs
+            .lock()
+            .unwrap()
+            .get(key)
+            .copied()
+            .unwrap_or(self.default_config)
+    }
+
+    fn state_for(&self, key: &str) -> Arc<KeyState> {
+        self.states
+            .lock()
+            .unwrap()
+            .entry(key.to_string())
+            .or_insert_with(|| Arc::new(KeyState::default()))
+            .clone()
+    }
+
+    /// Attempt to reserve capacity for `key` without waiting. Returns an informative error the
+    /// moment any configured limit would be exceeded (request shedding).
+    pub fn try_acquire(&self, key: &str, estimated_tokens: u64) -> Result<RateLimitPermit> {
+        let config = self.config_for(key);
+        let state = self.state_for(key);
+        state.roll_window_if_expired(now_secs());
+
+        if let Some(limit) = config.max_concurrent {
+            if state.in_flight.load(Ordering::SeqCst) >= limit {
+                return Err(Error::provider(
+                    key,
+                    format!("rate limit exceeded: {limit} concurrent request(s) already in flight"),
+                ));
+            }
+        }
+        if let Some(limit) = config.requests_per_minute {
+            if state.request_count.load(Ordering::SeqCst) >= limit {
+                return Err(Error::provider(
+                    key,
+                    format!("rate limit exceeded: {limit} requests/min"),
+                ));
+            }
+        }
+        if let Some(limit) = config.tokens_per_minute {
+            let projected = state.token_count.load(Ordering::SeqCst) + estimated_tokens;
+            if projected > u64::from(limit) {
+                return Err(Error::provider(
+                    key,
+                    format!("rate limit exceeded: {limit} tokens/min"),
+                ));
+            }
+        }
+
+        state.in_flight.fetch_add(1, Ordering::SeqCst);
+        state.request_count.fetch_add(1, Ordering::SeqCst);
+        state.token_count.fetch_add(estimated_tokens, Ordering::SeqCst);
+        Ok(RateLimitPermit { state })
+    }
+
+    /// Reserve capacity for `key`, queueing (polling) while any limit is saturated instead of
+    /// failing immediately. Gives up and sheds the request with an informative error once
+    /// `max_wait` has elapsed.
+    pub async fn acquire(
+        &self,
+        key: &str,
+        estimated_tokens: u64,
+        max_wait: Duration,
+    ) -> Result<RateLimitPermit> {
+        let deadline_secs = now_secs() + max_wait.as_secs().max(1);
+        loop {
+            match self.try_acquire(key, estimated_tokens) {
+                Ok(permit) => return Ok(permit),
+                Err(err) => {
+                    if now_secs() >= deadline_secs {
+                        return Err(err);
+                    }
+                    sleep(wall_now(), QUEUE_POLL_INTERVAL).await;
+                }
+            }
+        }
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asupersync::runtime::RuntimeBuilder;
+
+    #[test]
+    fn unlimited_config_never_sheds() {
+        let limiter = RateLimiter::new(RateLimitConfig::unlimited());
+        for _ in 0..50 {
+            limiter.try_acquire("anthropic", 1000).expect("unlimited acquire");
+        }
+    }
+
+    #[test]
+    fn concurrency_limit_sheds_and_frees_on_drop() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            max_concurrent: Some(1),
+            ..RateLimitConfig::unlimited()
+        });
+
+        let permit = limiter.try_acquire("anthropic/claude-opus-4", 0).expect("first acquire");
+        let err = limiter
+            .try_acquire("anthropic/claude-opus-4", 0)
+            .expect_err("second acquire should be shed");
+        assert!(err.to_string().contains("concurrent"));
+
+        drop(permit);
+        limiter
+            .try_acquire("anthropic/claude-opus-4", 0)
+            .expect("slot freed after drop");
+    }
+
+    #[test]
+    fn requests_per_minute_limit_sheds() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            requests_per_minute: Some(2),
+            ..RateLimitConfig::unlimited()
+        });
+
+        limiter.try_acquire("openai", 0).expect("first");
+        limiter.try_acquire("openai", 0).expect("second");
+        let err = limiter.try_acquire("openai", 0).expect_err("third should be shed");
+        assert!(err.to_string().contains("requests/min"));
+    }
+
+    #[test]
+    fn tokens_per_minute_limit_sheds() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            tokens_per_minute: Some(1000),
+            ..RateLimitConfig::unlimited()
+        });
+
+        limiter.try_acquire("gemini", 600).expect("within budget");
+        let err = limiter
+            .try_acquire("gemini", 600)
+            .expect_err("would exceed tokens/min");
+        assert!(err.to_string().contains("tokens/min"));
+    }
+
+    #[test]
+    fn per_key_config_overrides_default() {
+        let limiter = RateLimiter::new(RateLimitConfig::unlimited());
+        limiter.set_config(
+            "anthropic",
+            RateLimitConfig {
+                max_concurrent: Some(1),
+                ..RateLimitConfig::unlimited()
+            },
+        );
+
+        limiter.try_acquire("anthropic", 0).expect("first anthropic acquire");
+        limiter
+            .try_acquire("anthropic", 0)
+            .expect_err("second anthropic acquire should be shed");
+        limiter
+            .try_acquire("openai", 0)
+            .expect("unrelated key is unaffected");
+    }
+
+    #[test]
+    fn acquire_sheds_after_max_wait_elapses() {
+        let runtime = RuntimeBuilder::current_thread()
+            .build()
+            .expect("runtime build");
+
+        runtime.block_on(async move {
+            let limiter = RateLimiter::new(RateLimitConfig {
+                max_concurrent: Some(1),
+                ..RateLimitConfig::unlimited()
+            });
+            let _permit = limiter.try_acquire("cohere", 0).expect("hold the only slot");
+
+            let err = limiter
+                .acquire("cohere", 0, Duration::from_millis(50))
+                .await
+                .expect_err("should shed once max_wait elapses");
+            assert!(err.to_string().contains("concurrent"));
+        });
+    }
+}
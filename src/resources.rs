@@ -104,6 +104,10 @@ pub struct PromptTemplate {
     pub content: String,
     pub source: String,
     pub file_path: PathBuf,
+    /// Model override from frontmatter (`model: ...`), if the template pins one.
+    pub model: Option<String>,
+    /// Tool allowlist from frontmatter (`allowed-tools: a, b, c`), if restricted.
+    pub allowed_tools: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone)]
@@ -171,6 +175,8 @@ pub struct ResourceLoader {
     skill_diagnostics: Vec<ResourceDiagnostic>,
     prompts: Vec<PromptTemplate>,
     prompt_diagnostics: Vec<ResourceDiagnostic>,
+    commands: Vec<PromptTemplate>,
+    command_diagnostics: Vec<ResourceDiagnostic>,
     themes: Vec<ThemeResource>,
     theme_diagnostics: Vec<ResourceDiagnostic>,
     extensions: Vec<PathBuf>,
@@ -184,6 +190,8 @@ impl ResourceLoader {
             skill_diagnostics: Vec::new(),
             prompts: Vec::new(),
             prompt_diagnostics: Vec::new(),
+            commands: Vec::new(),
+            command_diagnostics: Vec::new(),
             themes: Vec::new(),
             theme_diagnostics: Vec::new(),
             extensions: Vec::new(),
@@ -252,11 +260,12 @@ impl ResourceLoader {
         extension_entries.extend(enabled_paths(cli_extensions.extensions));
         let extension_entries = dedupe_paths(extension_entries);
 
-        // Load skills, prompt templates, and themes in parallel — they are independent
-        // filesystem walks that benefit from overlapped I/O on multi-core machines.
+        // Load skills, prompt templates, user commands, and themes in parallel — they
+        // are independent filesystem walks that benefit from overlapped I/O on
+        // multi-core machines.
         let agent_dir = Config::global_dir();
         let cwd_buf = cwd.to_path_buf();
-        let (skills_join, prompts_join, themes_join) = std::thread::scope(|s| {
+        let (skills_join, prompts_join, commands_join, themes_join) = std::thread::scope(|s| {
             let cwd_s = &cwd_buf;
             let agent_s = &agent_dir;
             let skills_handle = s.spawn(move || {
@@ -275,6 +284,7 @@ impl ResourceLoader {
                     include_defaults: false,
                 })
             });
+            let commands_handle = s.spawn(move || load_user_commands(cwd_s, agent_s));
             let themes_handle = s.spawn(move || {
                 load_themes(LoadThemesOptions {
                     cwd: cwd_s.clone(),
@@ -286,6 +296,7 @@ impl ResourceLoader {
             (
                 skills_handle.join(),
                 prompts_handle.join(),
+                commands_handle.join(),
                 themes_handle.join(),
             )
         });
@@ -301,6 +312,12 @@ impl ResourceLoader {
                 panic_payload_message(payload)
             ))
         })?;
+        let command_templates = commands_join.map_err(|payload| {
+            Error::config(format!(
+                "Command loader thread panicked: {}",
+                panic_payload_message(payload)
+            ))
+        })?;
         let themes_result = themes_join.map_err(|payload| {
             Error::config(format!(
                 "Theme loader thread panicked: {}",
@@ -308,6 +325,7 @@ impl ResourceLoader {
             ))
         })?;
         let (prompts, prompt_diagnostics) = dedupe_prompts(prompt_templates);
+        let (commands, command_diagnostics) = dedupe_prompts(command_templates);
         let (themes, theme_diagnostics) = dedupe_themes(themes_result.themes);
         let mut theme_diags = themes_result.diagnostics;
         theme_diags.extend(theme_diagnostics);
@@ -317,6 +335,8 @@ impl ResourceLoader {
             skill_diagnostics: skills_result.diagnostics,
             prompts,
             prompt_diagnostics,
+            commands,
+            command_diagnostics,
             themes,
             theme_diagnostics: theme_diags,
             extensions: extension_entries,
@@ -344,6 +364,36 @@ impl ResourceLoader {
         &self.prompt_diagnostics
     }
 
+    /// User-defined slash commands loaded from `<agent_dir>/commands/` (by default
+    /// `~/.pi/agent/commands/`) and `.pi/commands/`.
+    pub fn commands(&self) -> &[PromptTemplate] {
+        &self.commands
+    }
+
+    pub fn command_diagnostics(&self) -> &[ResourceDiagnostic] {
+        &self.command_diagnostics
+    }
+
+    /// JSON view of user-defined commands, suitable for merging into
+    /// [`crate::extensions::ExtensionManager::list_commands`] alongside
+    /// extension-provided commands.
+    pub fn command_values(&self) -> Vec<Value> {
+        self.commands
+            .iter()
+            .map(|template| {
+                json!({
+                    "name": template.name,
+                    "description": template.description,
+                    "source": "user-command",
+                    "location": template.source,
+                    "path": template.file_path.display().to_string(),
+                    "model": template.model,
+                    "allowed_tools": template.allowed_tools,
+                })
+            })
+            .collect()
+    }
+
     pub fn themes(&self) -> &[ThemeResource] {
         &self.themes
     }
@@ -402,6 +452,8 @@ impl ResourceLoader {
             }));
         }
 
+        commands.extend(self.command_values());
+
         for skill in &self.skills {
             commands.push(json!({
                 "name": format!("skill:{}", skill.name),
@@ -420,7 +472,8 @@ impl ResourceLoader {
         if self.enable_skill_commands {
             expanded = expand_skill_command(&expanded, &self.skills);
         }
-        expand_prompt_template(&expanded, &self.prompts)
+        expanded = expand_prompt_template(&expanded, &self.prompts);
+        expand_prompt_template(&expanded, &self.commands)
     }
 }
 
@@ -1026,6 +1079,26 @@ pub fn load_prompt_templates(options: LoadPromptTemplatesOptions) -> Vec<PromptT
     templates
 }
 
+/// Load user-defined slash commands from `<agent_dir>/commands/` and
+/// `<cwd>/.pi/commands/`. These are markdown files with the same
+/// `$ARGUMENTS`/`$1`/`${1}` substitution and frontmatter as prompt templates,
+/// but are surfaced alongside extension-provided commands rather than as
+/// generic prompt templates.
+fn load_user_commands(cwd: &Path, agent_dir: &Path) -> Vec<PromptTemplate> {
+    let mut commands = Vec::new();
+    commands.extend(load_templates_from_dir(
+        &agent_dir.join("commands"),
+        "user",
+        "(user)",
+    ));
+    commands.extend(load_templates_from_dir(
+        &cwd.join(Config::project_dir()).join("commands"),
+        "project",
+        "(project)",
+    ));
+    commands
+}
+
 fn load_templates_from_dir(dir: &Path, source: &str, label: &str) -> Vec<PromptTemplate> {
     let mut templates = Vec::new();
     if !dir.exists() {
@@ -1088,12 +1161,23 @@ fn load_template_from_file(path: &Path, source: &str, label: &str) -> Option<Pro
         .unwrap_or("template")
         .to_string();
 
+    let model = parsed.frontmatter.get("model").cloned();
+    let allowed_tools = parsed.frontmatter.get("allowed-tools").map(|raw| {
+        raw.split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect()
+    });
+
     Some(PromptTemplate {
         name,
         description,
         content: parsed.body,
         source: source.to_string(),
         file_path: path.to_path_buf(),
+        model,
+        allowed_tools,
     })
 }
 
@@ -1382,6 +1466,12 @@ fn positional_arg_regex() -> &'static regex::Regex {
     RE.get_or_init(|| regex::Regex::new(r"\$(\d+)").expect("positional arg regex"))
 }
 
+/// Cached regex for the curly-brace positional form `${1}`, `${2}`, ….
+fn positional_arg_braced_regex() -> &'static regex::Regex {
+    static RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    RE.get_or_init(|| regex::Regex::new(r"\$\{(\d+)\}").expect("braced positional arg regex"))
+}
+
 /// Cached regex for `${@:start}` or `${@:start:length}` substitution.
 fn slice_arg_regex() -> &'static regex::Regex {
     static RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
@@ -1402,6 +1492,16 @@ pub fn substitute_args(content: &str, args: &[String]) -> String {
         }
     });
 
+    // Braced positional ${1}, ${2}, ...
+    result = replace_regex(&result, positional_arg_braced_regex(), |caps| {
+        let idx = caps[1].parse::<usize>().unwrap_or(0);
+        if idx == 0 {
+            String::new()
+        } else {
+            args.get(idx.saturating_sub(1)).cloned().unwrap_or_default()
+        }
+    });
+
     // ${@:start} or ${@:start:length}
     result = replace_regex(&result, slice_arg_regex(), |caps| {
         let mut start = caps[1].parse::<usize>().unwrap_or(1);
@@ -1665,6 +1765,8 @@ mod tests {
             content: "Review $1".to_string(),
             source: "user".to_string(),
             file_path: PathBuf::from("/tmp/review.md"),
+            model: None,
+            allowed_tools: None,
         };
         let out = expand_prompt_template("/review foo", &[template]);
         assert_eq!(out, "Review foo");
@@ -2005,6 +2107,8 @@ still frontmatter",
                 content: "content1".to_string(),
                 source: "a".to_string(),
                 file_path: PathBuf::from("/a/review.md"),
+                model: None,
+                allowed_tools: None,
             },
             PromptTemplate {
                 name: "review".to_string(),
@@ -2012,6 +2116,8 @@ still frontmatter",
                 content: "content2".to_string(),
                 source: "b".to_string(),
                 file_path: PathBuf::from("/b/review.md"),
+                model: None,
+                allowed_tools: None,
             },
             PromptTemplate {
                 name: "unique".to_string(),
@@ -2019,6 +2125,8 @@ still frontmatter",
                 content: "content3".to_string(),
                 source: "c".to_string(),
                 file_path: PathBuf::from("/c/unique.md"),
+                model: None,
+                allowed_tools: None,
             },
         ];
         let (deduped, diagnostics) = dedupe_prompts(prompts);
@@ -2037,6 +2145,8 @@ still frontmatter",
                 content: String::new(),
                 source: "s".to_string(),
                 file_path: PathBuf::from("/z.md"),
+                model: None,
+                allowed_tools: None,
             },
             PromptTemplate {
                 name: "a-prompt".to_string(),
@@ -2044,6 +2154,8 @@ still frontmatter",
                 content: String::new(),
                 source: "s".to_string(),
                 file_path: PathBuf::from("/a.md"),
+                model: None,
+                allowed_tools: None,
             },
         ];
         let (deduped, diagnostics) = dedupe_prompts(prompts);
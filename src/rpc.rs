@@ -58,6 +58,13 @@ fn provider_ids_match(left: &str, right: &str) -> bool {
         || left_canonical.eq_ignore_ascii_case(right_canonical)
 }
 
+/// Version of the line-delimited JSON protocol spoken by [`run`].
+///
+/// Bumped when a change to the command/response/event shapes would break
+/// existing clients; sent once as the `ready` event at the start of every
+/// connection.
+pub const RPC_PROTOCOL_VERSION: u32 = 1;
+
 #[derive(Clone)]
 pub struct RpcOptions {
     pub config: Config,
@@ -162,7 +169,7 @@ fn is_extension_command(message: &str, expanded: &str) -> bool {
     message.trim_start().starts_with('/') && message == expanded
 }
 
-fn try_send_line_with_backpressure(tx: &mpsc::Sender<String>, mut line: String) -> bool {
+pub(crate) fn try_send_line_with_backpressure(tx: &mpsc::Sender<String>, mut line: String) -> bool {
     loop {
         match tx.try_send(line) {
             Ok(()) => return true,
@@ -298,6 +305,163 @@ pub async fn run_stdio(mut session: AgentSession, options: RpcOptions) -> Result
     run(session, options, in_rx, out_tx).await
 }
 
+/// Serve the RPC protocol over a TCP socket bound to `addr` instead of stdio.
+///
+/// Accepts exactly one client connection (the first to connect), then speaks
+/// the same line-delimited JSON protocol as [`run_stdio`] over that socket.
+/// Suited to editors/CI bots that want to embed the agent without spawning
+/// it as a child process with piped stdio.
+pub async fn run_tcp(mut session: AgentSession, options: RpcOptions, addr: std::net::SocketAddr) -> Result<()> {
+    session.agent.set_queue_modes(
+        options.config.steering_queue_mode(),
+        options.config.follow_up_queue_mode(),
+    );
+
+    let listener = std::net::TcpListener::bind(addr)
+        .map_err(|err| Error::session(format!("failed to bind {addr}: {err}")))?;
+    let local_addr = listener.local_addr().unwrap_or(addr);
+    tracing::info!(
+        event = "pi.rpc.serve.listening",
+        transport = "tcp",
+        addr = %local_addr,
+        "RPC server listening for a client connection"
+    );
+
+    let (in_tx, in_rx) = mpsc::channel::<String>(1024);
+    let (out_tx, out_rx) = std::sync::mpsc::channel::<String>();
+
+    std::thread::spawn(move || {
+        let Ok((stream, peer)) = listener.accept() else {
+            return;
+        };
+        tracing::info!(
+            event = "pi.rpc.serve.connected",
+            transport = "tcp",
+            peer = %peer,
+            "RPC client connected"
+        );
+
+        let Ok(reader_stream) = stream.try_clone() else {
+            return;
+        };
+
+        std::thread::spawn(move || {
+            let mut reader = io::BufReader::new(reader_stream);
+            let mut line = String::new();
+            loop {
+                line.clear();
+                match reader.read_line(&mut line) {
+                    Ok(0) | Err(_) => break,
+                    Ok(_) => {
+                        let line_to_send = std::mem::take(&mut line);
+                        if !try_send_line_with_backpressure(&in_tx, line_to_send) {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        let mut writer = stream;
+        for line in out_rx {
+            if writer.write_all(line.as_bytes()).is_err() {
+                break;
+            }
+            if writer.write_all(b"\n").is_err() {
+                break;
+            }
+            if writer.flush().is_err() {
+                break;
+            }
+        }
+    });
+
+    run(session, options, in_rx, out_tx).await
+}
+
+/// Serve the RPC protocol over a localhost Unix domain socket instead of
+/// stdio. See [`run_tcp`] for the connection model (one client, same wire
+/// protocol as [`run_stdio`]).
+#[cfg(unix)]
+pub async fn run_unix(mut session: AgentSession, options: RpcOptions, path: PathBuf) -> Result<()> {
+    use std::os::unix::net::UnixListener;
+
+    session.agent.set_queue_modes(
+        options.config.steering_queue_mode(),
+        options.config.follow_up_queue_mode(),
+    );
+
+    // A stale socket file from a previous run would otherwise make bind() fail.
+    let _ = std::fs::remove_file(&path);
+    let listener = UnixListener::bind(&path)
+        .map_err(|err| Error::session(format!("failed to bind {}: {err}", path.display())))?;
+    tracing::info!(
+        event = "pi.rpc.serve.listening",
+        transport = "unix",
+        path = %path.display(),
+        "RPC server listening for a client connection"
+    );
+
+    let (in_tx, in_rx) = mpsc::channel::<String>(1024);
+    let (out_tx, out_rx) = std::sync::mpsc::channel::<String>();
+
+    std::thread::spawn(move || {
+        let Ok((stream, _peer)) = listener.accept() else {
+            return;
+        };
+        tracing::info!(
+            event = "pi.rpc.serve.connected",
+            transport = "unix",
+            "RPC client connected"
+        );
+
+        let Ok(reader_stream) = stream.try_clone() else {
+            return;
+        };
+
+        std::thread::spawn(move || {
+            let mut reader = io::BufReader::new(reader_stream);
+            let mut line = String::new();
+            loop {
+                line.clear();
+                match reader.read_line(&mut line) {
+                    Ok(0) | Err(_) => break,
+                    Ok(_) => {
+                        let line_to_send = std::mem::take(&mut line);
+                        if !try_send_line_with_backpressure(&in_tx, line_to_send) {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        let mut writer = stream;
+        for line in out_rx {
+            if writer.write_all(line.as_bytes()).is_err() {
+                break;
+            }
+            if writer.write_all(b"\n").is_err() {
+                break;
+            }
+            if writer.flush().is_err() {
+                break;
+            }
+        }
+    });
+
+    run(session, options, in_rx, out_tx).await
+}
+
+/// Non-Unix fallback: Unix domain sockets are not available on this platform.
+#[cfg(not(unix))]
+pub async fn run_unix(_session: AgentSession, _options: RpcOptions, path: PathBuf) -> Result<()> {
+    Err(Error::session(format!(
+        "Unix domain sockets are not supported on this platform (requested path: {})",
+        path.display()
+    )))
+}
+
 #[allow(clippy::too_many_lines)]
 #[allow(
     clippy::significant_drop_tightening,
@@ -431,6 +595,11 @@ pub async fn run(
         });
     }
 
+    let _ = out_tx.send(event(&json!({
+        "type": "ready",
+        "protocolVersion": RPC_PROTOCOL_VERSION,
+    })));
+
     while let Ok(line) = in_rx.recv(&cx).await {
         if line.trim().is_empty() {
             continue;
@@ -852,6 +1021,17 @@ pub async fn run(
                             .as_ref()
                             .map(crate::extensions::ExtensionRegion::manager),
                     )?;
+                    let provider_impl = providers::apply_rate_limit(
+                        provider_impl,
+                        &entry,
+                        options.config.rate_limits.as_ref(),
+                    );
+                    let provider_impl = providers::apply_provider_cache(
+                        provider_impl,
+                        options.config.provider_cache.as_ref(),
+                        false,
+                        &Config::global_dir().join("provider-cache"),
+                    );
                     guard.agent.set_provider(provider_impl);
                     guard.agent.stream_options_mut().api_key.clone_from(&key);
                     guard
@@ -3837,6 +4017,17 @@ async fn cycle_model_for_rpc(
             .as_ref()
             .map(crate::extensions::ExtensionRegion::manager),
     )?;
+    let provider_impl = crate::providers::apply_rate_limit(
+        provider_impl,
+        &next_entry,
+        options.config.rate_limits.as_ref(),
+    );
+    let provider_impl = crate::providers::apply_provider_cache(
+        provider_impl,
+        options.config.provider_cache.as_ref(),
+        false,
+        &Config::global_dir().join("provider-cache"),
+    );
     guard.agent.set_provider(provider_impl);
 
     let key = resolve_model_key(&options.auth, &next_entry);
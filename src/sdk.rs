@@ -278,6 +278,8 @@ pub struct SessionOptions {
     pub repair_policy: Option<String>,
     pub include_cwd_in_prompt: bool,
     pub max_tool_iterations: usize,
+    /// Bypass the on-disk provider response cache even when `Config.provider_cache` is enabled.
+    pub no_cache: bool,
 
     /// Session-level event listener invoked for every [`AgentEvent`].
     ///
@@ -314,6 +316,7 @@ impl Default for SessionOptions {
             repair_policy: None,
             include_cwd_in_prompt: true,
             max_tool_iterations: 50,
+            no_cache: false,
             on_event: None,
             on_tool_start: None,
             on_tool_end: None,
@@ -1483,6 +1486,7 @@ pub async fn create_agent_session(options: SessionOptions) -> Result<AgentSessio
     let mut cli = Cli::try_parse_from(["pi"])
         .map_err(|e| Error::validation(format!("CLI init failed: {e}")))?;
     cli.no_session = options.no_session;
+    cli.no_cache = options.no_cache;
     cli.provider = options.provider.clone();
     cli.model = options.model.clone();
     cli.api_key = options.api_key.clone();
@@ -1562,6 +1566,13 @@ pub async fn create_agent_session(options: SessionOptions) -> Result<AgentSessio
 
     let provider = providers::create_provider(&selection.model_entry, None)
         .map_err(|e| Error::provider("sdk", e.to_string()))?;
+    let provider = providers::apply_rate_limit(provider, &selection.model_entry, config.rate_limits.as_ref());
+    let provider = providers::apply_provider_cache(
+        provider,
+        config.provider_cache.as_ref(),
+        cli.no_cache,
+        &global_dir.join("provider-cache"),
+    );
 
     let api_key = auth
         .resolve_api_key(
@@ -1622,6 +1633,7 @@ pub async fn create_agent_session(options: SessionOptions) -> Result<AgentSessio
                 Some(resolved_ext_policy.policy),
                 Some(resolved_repair_policy.effective_mode),
                 None,
+                None,
             )
             .await?;
     }
@@ -2902,6 +2902,9 @@ pub struct SessionHeader {
         alias = "parentSession"
     )]
     pub parent_session: Option<String>,
+    /// Name of the active agent profile ("mode"), if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_profile: Option<String>,
 }
 
 impl SessionHeader {
@@ -2919,6 +2922,7 @@ impl SessionHeader {
             model_id: None,
             thinking_level: None,
             parent_session: None,
+            active_profile: None,
         }
     }
 }
@@ -259,6 +259,21 @@ impl SseParser {
         events
     }
 
+    /// Feed data to the parser, invoking `on_event` for each complete event
+    /// as it is produced instead of collecting into a `Vec`.
+    ///
+    /// Providers that decode each event's `data` straight into a typed delta
+    /// struct (see `providers::anthropic::process_event` and friends) should
+    /// prefer this over [`Self::feed`]: it skips the intermediate `Vec`
+    /// allocation on every network chunk, which matters on long streaming
+    /// responses where a chunk carries a single small delta.
+    pub fn for_each_event<F>(&mut self, data: &str, on_event: F)
+    where
+        F: FnMut(SseEvent),
+    {
+        self.feed_into(data, on_event);
+    }
+
     /// Check if the parser has any pending data.
     pub fn has_pending(&self) -> bool {
         !self.buffer.is_empty() || self.has_data
@@ -840,6 +855,16 @@ mod tests {
         assert_eq!(events[1].data, "second");
     }
 
+    #[test]
+    fn test_for_each_event_matches_feed() {
+        let mut parser = SseParser::new();
+        let mut seen = Vec::new();
+        parser.for_each_event("data: first\n\ndata: second\n\n", |event| {
+            seen.push(event.data);
+        });
+        assert_eq!(seen, vec!["first".to_string(), "second".to_string()]);
+    }
+
     #[test]
     fn test_incremental_feed() {
         let mut parser = SseParser::new();
@@ -1,17 +1,22 @@
 //! Built-in tool implementations.
 //!
-//! Pi provides 7 built-in tools: read, bash, edit, write, grep, find, ls.
+//! Pi provides 8 built-in tools: read, bash, edit, write, grep, find, ls, task.
 //!
 //! Tools are exposed to the model via JSON Schema (see [`crate::provider::ToolDef`]) and executed
 //! locally by the agent loop. Each tool returns structured [`ContentBlock`] output suitable for
 //! rendering in the TUI and for inclusion in provider messages as tool results.
 
+use crate::agent::{Agent, AgentConfig, AgentSession};
 use crate::agent_cx::AgentCx;
+use crate::compaction::ResolvedCompactionSettings;
 use crate::config::Config;
 use crate::error::{Error, Result};
 use crate::extensions::strip_unc_prefix;
 use crate::model::{ContentBlock, ImageContent, TextContent};
+use crate::provider::Provider;
+use crate::session::Session;
 use asupersync::io::{AsyncRead, AsyncReadExt, AsyncWriteExt, ReadBuf, SeekFrom};
+use asupersync::sync::Mutex as AsyncMutex;
 use asupersync::time::{sleep, wall_now};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -20,7 +25,7 @@ use std::fmt::Write as _;
 use std::io::{BufRead, Read, Write};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
-use std::sync::{OnceLock, mpsc};
+use std::sync::{Arc, OnceLock, mpsc};
 use std::thread;
 use std::time::{Duration, Instant};
 use unicode_normalization::UnicodeNormalization;
@@ -1190,6 +1195,7 @@ impl ToolRegistry {
         let block_images = config
             .and_then(|c| c.images.as_ref().and_then(|i| i.block_images))
             .unwrap_or(false);
+        let auto_format = config.is_some_and(Config::auto_format);
 
         for name in enabled {
             match *name {
@@ -1203,8 +1209,12 @@ impl ToolRegistry {
                     shell_path.clone(),
                     shell_command_prefix.clone(),
                 ))),
-                "edit" => tools.push(Box::new(EditTool::new(cwd))),
-                "write" => tools.push(Box::new(WriteTool::new(cwd))),
+                "edit" => tools.push(Box::new(
+                    EditTool::new(cwd).with_formatters(crate::formatters::FormatterPipeline::new(auto_format)),
+                )),
+                "write" => tools.push(Box::new(
+                    WriteTool::new(cwd).with_formatters(crate::formatters::FormatterPipeline::new(auto_format)),
+                )),
                 "grep" => tools.push(Box::new(GrepTool::new(cwd))),
                 "find" => tools.push(Box::new(FindTool::new(cwd))),
                 "ls" => tools.push(Box::new(LsTool::new(cwd))),
@@ -2112,14 +2122,23 @@ struct EditInput {
 
 pub struct EditTool {
     cwd: PathBuf,
+    formatters: crate::formatters::FormatterPipeline,
 }
 
 impl EditTool {
     pub fn new(cwd: &Path) -> Self {
         Self {
             cwd: cwd.to_path_buf(),
+            formatters: crate::formatters::FormatterPipeline::default(),
         }
     }
+
+    /// Enable running the post-write formatter pipeline after each edit.
+    #[must_use]
+    pub fn with_formatters(mut self, formatters: crate::formatters::FormatterPipeline) -> Self {
+        self.formatters = formatters;
+        self
+    }
 }
 
 fn strip_bom(s: &str) -> (&str, bool) {
@@ -2869,11 +2888,31 @@ impl Tool for EditTool {
             );
         }
 
+        let mut message = format!("Successfully replaced text in {}.", input.path);
+        match self.formatters.run(&absolute_path) {
+            Ok(Some(result)) if result.error.is_none() && result.diff.is_some() => {
+                let _ = write!(message, " (auto-formatted with {})", result.formatter);
+                details.insert(
+                    "formatted_by".to_string(),
+                    serde_json::Value::String(result.formatter.to_string()),
+                );
+            }
+            Ok(Some(result)) if result.error.is_some() => {
+                tracing::warn!(
+                    formatter = result.formatter,
+                    error = result.error.as_deref(),
+                    "Post-edit formatter failed"
+                );
+                details.insert(
+                    "formatter_error".to_string(),
+                    serde_json::json!({ "formatter": result.formatter, "message": result.error }),
+                );
+            }
+            _ => {}
+        }
+
         Ok(ToolOutput {
-            content: vec![ContentBlock::Text(TextContent::new(format!(
-                "Successfully replaced text in {}.",
-                input.path
-            )))],
+            content: vec![ContentBlock::Text(TextContent::new(message))],
             details: Some(serde_json::Value::Object(details)),
             is_error: false,
         })
@@ -2894,14 +2933,23 @@ struct WriteInput {
 
 pub struct WriteTool {
     cwd: PathBuf,
+    formatters: crate::formatters::FormatterPipeline,
 }
 
 impl WriteTool {
     pub fn new(cwd: &Path) -> Self {
         Self {
             cwd: cwd.to_path_buf(),
+            formatters: crate::formatters::FormatterPipeline::default(),
         }
     }
+
+    /// Enable running the post-write formatter pipeline after each write.
+    #[must_use]
+    pub fn with_formatters(mut self, formatters: crate::formatters::FormatterPipeline) -> Self {
+        self.formatters = formatters;
+        self
+    }
 }
 
 #[async_trait]
@@ -2995,12 +3043,29 @@ impl Tool for WriteTool {
             .persist(&path)
             .map_err(|e| Error::tool("write", format!("Failed to persist file: {e}")))?;
 
+        let mut message = format!("Successfully wrote {} bytes to {}", bytes_written, input.path);
+        let mut details = None;
+        match self.formatters.run(&path) {
+            Ok(Some(result)) if result.error.is_none() && result.diff.is_some() => {
+                let _ = write!(message, " (auto-formatted with {})", result.formatter);
+                details = Some(serde_json::json!({ "formatted_by": result.formatter }));
+            }
+            Ok(Some(result)) if result.error.is_some() => {
+                tracing::warn!(
+                    formatter = result.formatter,
+                    error = result.error.as_deref(),
+                    "Post-write formatter failed"
+                );
+                details = Some(serde_json::json!({
+                    "formatter_error": { "formatter": result.formatter, "message": result.error },
+                }));
+            }
+            _ => {}
+        }
+
         Ok(ToolOutput {
-            content: vec![ContentBlock::Text(TextContent::new(format!(
-                "Successfully wrote {} bytes to {}",
-                bytes_written, input.path
-            )))],
-            details: None,
+            content: vec![ContentBlock::Text(TextContent::new(message))],
+            details,
             is_error: false,
         })
     }
@@ -4056,6 +4121,204 @@ impl Tool for LsTool {
     }
 }
 
+// ============================================================================
+// Task Tool
+// ============================================================================
+
+/// Tool names given to a sub-agent when the `task` call omits `tools`.
+const DEFAULT_TASK_TOOLS: &[&str] = &["read", "grep", "find", "ls"];
+
+/// Tool-call iterations given to a sub-agent when the `task` call omits `maxTurns`.
+const DEFAULT_TASK_MAX_TURNS: usize = 20;
+
+/// Narrow `requested` down to the tools the parent session is itself allowed
+/// to use, silently dropping anything else rather than erroring -- a task
+/// call can only ever de-escalate privileges, never escalate them.
+fn filter_to_allowed_tools(requested: Vec<String>, allowed: &[String]) -> Vec<String> {
+    requested
+        .into_iter()
+        .filter(|name| allowed.iter().any(|allowed_name| allowed_name == name))
+        .collect()
+}
+
+/// Input parameters for the task tool.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TaskInput {
+    prompt: String,
+    #[serde(default)]
+    system_prompt: Option<String>,
+    #[serde(default)]
+    tools: Option<Vec<String>>,
+    #[serde(default)]
+    max_turns: Option<usize>,
+}
+
+/// Spawns a child agent to run a self-contained task to completion.
+///
+/// The child gets its own session, a restricted toolset, and a turn budget, so
+/// it can't run away with the parent's tools or conversation history. The
+/// child session is persisted and linked to the parent via
+/// [`Session::set_branched_from`] so it can be inspected later.
+pub struct TaskTool {
+    provider: Arc<dyn Provider>,
+    cwd: PathBuf,
+    config: Arc<Config>,
+    parent_session: Arc<AsyncMutex<Session>>,
+    /// The parent session's own enabled-tools set. A `task` call can only ever
+    /// narrow this, never escalate past it -- otherwise a model restricted to
+    /// e.g. `--tools read,grep` could hand its sub-agent `bash`/`write`/`edit`
+    /// just by asking for them in the tool call.
+    allowed_tools: Vec<String>,
+}
+
+impl TaskTool {
+    pub fn new(
+        provider: Arc<dyn Provider>,
+        cwd: PathBuf,
+        config: Arc<Config>,
+        parent_session: Arc<AsyncMutex<Session>>,
+        allowed_tools: Vec<String>,
+    ) -> Self {
+        Self {
+            provider,
+            cwd,
+            config,
+            parent_session,
+            allowed_tools,
+        }
+    }
+}
+
+#[async_trait]
+#[allow(clippy::unnecessary_literal_bound)]
+impl Tool for TaskTool {
+    fn name(&self) -> &str {
+        "task"
+    }
+    fn label(&self) -> &str {
+        "task"
+    }
+    fn description(&self) -> &str {
+        "Delegate a self-contained task to a sub-agent. The sub-agent runs to completion in its own session with a restricted toolset and turn budget, then returns a summary of what it found or did. Use this to isolate exploratory or multi-step work that would otherwise clutter the main conversation."
+    }
+
+    fn parameters(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "prompt": {
+                    "type": "string",
+                    "description": "The task for the sub-agent to complete"
+                },
+                "systemPrompt": {
+                    "type": "string",
+                    "description": "Optional system prompt for the sub-agent (default: none)"
+                },
+                "tools": {
+                    "type": "array",
+                    "items": {"type": "string"},
+                    "description": "Tool names the sub-agent may use (default: read, grep, find, ls)"
+                },
+                "maxTurns": {
+                    "type": "integer",
+                    "description": "Maximum tool-call iterations for the sub-agent (default: 20)"
+                }
+            },
+            "required": ["prompt"]
+        })
+    }
+
+    async fn execute(
+        &self,
+        _tool_call_id: &str,
+        input: serde_json::Value,
+        _on_update: Option<Box<dyn Fn(ToolUpdate) + Send + Sync>>,
+    ) -> Result<ToolOutput> {
+        let input: TaskInput = serde_json::from_value(input)
+            .map_err(|e| Error::tool("task", format!("Invalid input: {e}")))?;
+
+        let requested_tools = input
+            .tools
+            .unwrap_or_else(|| DEFAULT_TASK_TOOLS.iter().map(ToString::to_string).collect());
+        let tool_names = filter_to_allowed_tools(requested_tools, &self.allowed_tools);
+        let tool_refs: Vec<&str> = tool_names.iter().map(String::as_str).collect();
+        let child_tools = ToolRegistry::new(&tool_refs, &self.cwd, Some(&self.config));
+        let agent_config = AgentConfig {
+            system_prompt: input.system_prompt,
+            max_tool_iterations: input.max_turns.unwrap_or(DEFAULT_TASK_MAX_TURNS),
+            ..AgentConfig::default()
+        };
+        let agent = Agent::new(Arc::clone(&self.provider), child_tools, agent_config);
+
+        let parent_path = {
+            let cx = AgentCx::for_request();
+            let parent = self
+                .parent_session
+                .lock(cx.cx())
+                .await
+                .map_err(|e| Error::tool("task", e.to_string()))?;
+            parent.path.as_ref().map(|p| p.display().to_string())
+        };
+
+        let mut child_session = Session::create_with_dir(None);
+        child_session.set_branched_from(parent_path);
+        let child_session = Arc::new(AsyncMutex::new(child_session));
+        let mut child_agent_session = AgentSession::new(
+            agent,
+            Arc::clone(&child_session),
+            true,
+            ResolvedCompactionSettings::default(),
+        );
+
+        let outcome = child_agent_session
+            .run_text_with_abort(input.prompt, None, |_event| {})
+            .await;
+
+        {
+            let cx = AgentCx::for_request();
+            if let Ok(mut session) = child_session.lock(cx.cx()).await {
+                let _ = session.flush_autosave_on_shutdown().await;
+            }
+        }
+        let child_path = {
+            let cx = AgentCx::for_request();
+            child_session
+                .lock(cx.cx())
+                .await
+                .ok()
+                .and_then(|session| session.path.as_ref().map(|p| p.display().to_string()))
+        };
+
+        match outcome {
+            Ok(message) => {
+                let summary = message
+                    .content
+                    .iter()
+                    .filter_map(|block| match block {
+                        ContentBlock::Text(text) => Some(text.text.as_str()),
+                        _ => None,
+                    })
+                    .collect::<String>();
+                let details =
+                    child_path.map(|path| serde_json::json!({ "childSessionPath": path }));
+                Ok(ToolOutput {
+                    content: vec![ContentBlock::Text(TextContent::new(summary))],
+                    details,
+                    is_error: false,
+                })
+            }
+            Err(e) => Ok(ToolOutput {
+                content: vec![ContentBlock::Text(TextContent::new(format!(
+                    "Sub-agent task failed: {e}"
+                )))],
+                details: None,
+                is_error: true,
+            }),
+        }
+    }
+}
+
 // ============================================================================
 // Cleanup
 // ============================================================================
@@ -6891,4 +7154,117 @@ mod tests {
             assert_eq!(new_content, "line1\r\nchanged\r\nline3");
         });
     }
+
+    struct TaskStubProvider;
+
+    #[async_trait]
+    #[allow(clippy::unnecessary_literal_bound)]
+    impl Provider for TaskStubProvider {
+        fn name(&self) -> &str {
+            "test-provider"
+        }
+        fn api(&self) -> &str {
+            "test-api"
+        }
+        fn model_id(&self) -> &str {
+            "test-model"
+        }
+        async fn stream(
+            &self,
+            _context: &crate::provider::Context<'_>,
+            _options: &crate::provider::StreamOptions,
+        ) -> Result<
+            std::pin::Pin<Box<dyn futures::Stream<Item = Result<crate::provider::StreamEvent>> + Send>>,
+        > {
+            let message = crate::model::AssistantMessage {
+                content: vec![ContentBlock::Text(TextContent::new("subagent result"))],
+                api: "test-api".to_string(),
+                provider: "test-provider".to_string(),
+                model: "test-model".to_string(),
+                usage: crate::model::Usage::default(),
+                stop_reason: crate::model::StopReason::Stop,
+                error_message: None,
+                timestamp: 0,
+            };
+            let partial = crate::model::AssistantMessage {
+                content: vec![],
+                ..message.clone()
+            };
+            let events = vec![
+                Ok(crate::provider::StreamEvent::Start { partial }),
+                Ok(crate::provider::StreamEvent::Done {
+                    reason: crate::model::StopReason::Stop,
+                    message,
+                }),
+            ];
+            Ok(Box::pin(futures::stream::iter(events)))
+        }
+    }
+
+    #[test]
+    fn test_task_tool_executes_child_agent_and_returns_summary() {
+        asupersync::test_utils::run_test(|| async {
+            let tmp = tempfile::tempdir().unwrap();
+            let provider: Arc<dyn Provider> = Arc::new(TaskStubProvider);
+            let parent_session = Arc::new(AsyncMutex::new(Session::in_memory()));
+            let tool = TaskTool::new(
+                provider,
+                tmp.path().to_path_buf(),
+                Arc::new(Config::default()),
+                parent_session,
+                vec![
+                    "read".to_string(),
+                    "grep".to_string(),
+                    "find".to_string(),
+                    "ls".to_string(),
+                ],
+            );
+
+            let out = tool
+                .execute("t", serde_json::json!({ "prompt": "do the thing" }), None)
+                .await
+                .unwrap();
+
+            assert!(!out.is_error);
+            let ContentBlock::Text(text) = &out.content[0] else {
+                panic!("expected text content");
+            };
+            assert_eq!(text.text, "subagent result");
+        });
+    }
+
+    #[test]
+    fn test_task_tool_rejects_missing_prompt() {
+        asupersync::test_utils::run_test(|| async {
+            let tmp = tempfile::tempdir().unwrap();
+            let provider: Arc<dyn Provider> = Arc::new(TaskStubProvider);
+            let parent_session = Arc::new(AsyncMutex::new(Session::in_memory()));
+            let tool = TaskTool::new(
+                provider,
+                tmp.path().to_path_buf(),
+                Arc::new(Config::default()),
+                parent_session,
+                vec![
+                    "read".to_string(),
+                    "grep".to_string(),
+                    "find".to_string(),
+                    "ls".to_string(),
+                ],
+            );
+
+            let err = tool
+                .execute("t", serde_json::json!({}), None)
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("Invalid input"));
+        });
+    }
+
+    #[test]
+    fn test_filter_to_allowed_tools_drops_unauthorized_requests() {
+        let allowed = vec!["read".to_string(), "grep".to_string()];
+        let requested = vec!["read".to_string(), "bash".to_string(), "write".to_string()];
+        let filtered = filter_to_allowed_tools(requested, &allowed);
+        assert_eq!(filtered, vec!["read".to_string()]);
+    }
 }
@@ -0,0 +1,184 @@
+//! Multi-root workspace support.
+//!
+//! A session is normally scoped to a single project directory, but some
+//! setups (an app repo plus one or more shared library checkouts) need the
+//! agent to reason about several directories as one logical workspace. This
+//! module defines [`WorkspaceRoots`], a small labeled-roots registry that
+//! [`crate::extensions::FsScopes`] and friends can build allow-lists from,
+//! and that callers use to label results by root.
+
+use crate::error::{Error, Result};
+use std::path::{Path, PathBuf};
+
+/// A single named root within a multi-root workspace.
+///
+/// The label defaults to the directory's file name (e.g. `app`, `shared`)
+/// but can be overridden, which matters once two roots share a base name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceRoot {
+    pub label: String,
+    pub path: PathBuf,
+}
+
+/// An ordered set of workspace roots. Registering the same path twice keeps the
+/// first entry; lookups pick the most specific root containing a target.
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceRoots {
+    roots: Vec<WorkspaceRoot>,
+}
+
+impl WorkspaceRoots {
+    /// A single-root workspace scoped to `cwd`, labeled `"."`.
+    pub fn single(cwd: impl AsRef<Path>) -> Result<Self> {
+        let mut roots = Self::default();
+        roots.push(".", cwd)?;
+        Ok(roots)
+    }
+
+    /// Build a workspace from `(label, path)` pairs, canonicalizing each path.
+    pub fn from_pairs<I, S, P>(pairs: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (S, P)>,
+        S: Into<String>,
+        P: AsRef<Path>,
+    {
+        let mut roots = Self::default();
+        for (label, path) in pairs {
+            roots.push(label, path)?;
+        }
+        Ok(roots)
+    }
+
+    /// Build a workspace from `--workspace-root` style specs, each either `label=path` or a bare
+    /// `path` (label derived from the directory name).
+    pub fn from_specs<I, S>(specs: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut roots = Self::default();
+        for spec in specs {
+            let spec = spec.as_ref();
+            match spec.split_once('=') {
+                Some((label, path)) => roots.push(label, path)?,
+                None => roots.push("", spec)?,
+            }
+        }
+        Ok(roots)
+    }
+
+    /// Register an additional root, deriving a label from the directory name
+    /// when `label` is empty.
+    pub fn push(&mut self, label: impl Into<String>, path: impl AsRef<Path>) -> Result<()> {
+        let canonical = std::fs::canonicalize(path.as_ref())
+            .map_err(|err| Error::validation(format!("workspace root: {err}")))?;
+        let mut label = label.into();
+        if label.is_empty() {
+            label = canonical.file_name().map_or_else(
+                || canonical.to_string_lossy().into_owned(),
+                |name| name.to_string_lossy().into_owned(),
+            );
+        }
+        if self.roots.iter().any(|root| root.path == canonical) {
+            return Ok(());
+        }
+        self.roots.push(WorkspaceRoot {
+            label,
+            path: canonical,
+        });
+        Ok(())
+    }
+
+    pub fn roots(&self) -> &[WorkspaceRoot] {
+        &self.roots
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// All canonical root paths, in registration order.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.roots.iter().map(|root| root.path.as_path())
+    }
+
+    /// The most specific (longest-path) root that contains `target`, if any.
+    pub fn root_for(&self, target: &Path) -> Option<&WorkspaceRoot> {
+        self.roots
+            .iter()
+            .filter(|root| target.starts_with(&root.path))
+            .max_by_key(|root| root.path.as_os_str().len())
+    }
+
+    /// The label of the root containing `target`, or `None` if it falls
+    /// outside every registered root.
+    pub fn label_for(&self, target: &Path) -> Option<&str> {
+        self.root_for(target).map(|root| root.label.as_str())
+    }
+
+    /// The first-registered root, used as the default cwd for tools that
+    /// only understand a single directory.
+    pub fn primary(&self) -> Option<&WorkspaceRoot> {
+        self.roots.first()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_root_labels_as_dot() {
+        let dir = std::env::temp_dir();
+        let roots = WorkspaceRoots::single(&dir).expect("single root");
+        assert_eq!(roots.roots().len(), 1);
+        assert_eq!(roots.roots()[0].label, ".");
+    }
+
+    #[test]
+    fn root_for_prefers_most_specific_match() {
+        let base = std::env::temp_dir();
+        let nested = base.join("pi_workspace_roots_test_nested");
+        std::fs::create_dir_all(&nested).expect("create nested dir");
+
+        let roots =
+            WorkspaceRoots::from_pairs([("base", base.as_path()), ("nested", nested.as_path())])
+                .expect("build roots");
+
+        let label = roots.label_for(&nested.join("file.rs"));
+        assert_eq!(label, Some("nested"));
+
+        std::fs::remove_dir_all(&nested).ok();
+    }
+
+    #[test]
+    fn label_for_returns_none_outside_roots() {
+        let dir = std::env::temp_dir();
+        let roots = WorkspaceRoots::single(&dir).expect("single root");
+        let outside = Path::new("/definitely/not/a/root/file.txt");
+        assert_eq!(roots.label_for(outside), None);
+    }
+
+    #[test]
+    fn push_dedupes_identical_canonical_paths() {
+        let dir = std::env::temp_dir();
+        let mut roots = WorkspaceRoots::single(&dir).expect("single root");
+        roots.push("dup", &dir).expect("push duplicate");
+        assert_eq!(roots.roots().len(), 1);
+    }
+
+    #[test]
+    fn from_specs_parses_labeled_and_bare_paths() {
+        let dir = std::env::temp_dir();
+        let bare = dir.join("pi_workspace_roots_test_bare");
+        std::fs::create_dir_all(&bare).expect("create bare dir");
+        let spec = format!("shared={}", dir.display());
+        let bare_spec = bare.display().to_string();
+        let roots = WorkspaceRoots::from_specs([spec.as_str(), bare_spec.as_str()])
+            .expect("build roots");
+        assert_eq!(roots.roots()[0].label, "shared");
+        assert_eq!(roots.roots()[1].label, "pi_workspace_roots_test_bare");
+
+        std::fs::remove_dir_all(&bare).ok();
+    }
+}
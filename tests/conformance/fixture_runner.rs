@@ -307,6 +307,18 @@ fn command_value(command: Option<&Commands>) -> Value {
             "path": path,
             "dry_run": dry_run,
         }),
+        Some(Commands::Run {
+            prompt_file,
+            output,
+            concurrency,
+            checkpoint,
+        }) => json!({
+            "name": "run",
+            "prompt_file": prompt_file,
+            "output": output,
+            "concurrency": concurrency,
+            "checkpoint": checkpoint,
+        }),
         None => Value::Null,
     }
 }
@@ -108,45 +108,14 @@ fn get_str<'a>(v: &'a V, pointer: &str) -> &'a str {
     v.pointer(pointer).and_then(V::as_str).unwrap_or("unknown")
 }
 
+// `pi::qa` is the source of truth for must_pass gate parsing/validation; kept
+// as thin re-exports here so the rest of this file doesn't need to change.
 fn parse_must_pass_gate_verdict(v: &V) -> (String, u64, u64) {
-    let status = match get_str(v, "/status") {
-        "unknown" => get_str(v, "/verdict").to_string(),
-        value => value.to_string(),
-    };
-
-    let total = match get_u64(v, "/observed/must_pass_total") {
-        0 => get_u64(v, "/total"),
-        value => value,
-    };
-    let passed = match get_u64(v, "/observed/must_pass_passed") {
-        0 => get_u64(v, "/passed"),
-        value => value,
-    };
-
-    (status, passed, total)
+    pi::qa::parse_must_pass_gate_verdict(v)
 }
 
 fn validate_must_pass_gate_metadata(v: &V) -> Vec<String> {
-    let mut errors = Vec::new();
-
-    let schema = get_str(v, "/schema");
-    if schema != MUST_PASS_GATE_SCHEMA {
-        errors.push(format!(
-            "schema must be {MUST_PASS_GATE_SCHEMA}, found {schema}"
-        ));
-    }
-
-    for field in ["/generated_at", "/run_id", "/correlation_id"] {
-        if get_str(v, field) == "unknown" {
-            errors.push(format!("missing required field: {field}"));
-        }
-    }
-
-    if v.pointer("/observed").is_none() {
-        errors.push("missing required object: /observed".to_string());
-    }
-
-    errors
+    pi::qa::validate_must_pass_gate_metadata(v)
 }
 
 #[allow(clippy::too_many_lines)]
@@ -163,6 +163,7 @@ fn proptest_session_header() -> impl Strategy<Value = SessionHeader> {
                 model_id,
                 thinking_level,
                 parent_session,
+                active_profile: None,
             },
         )
 }
@@ -264,6 +265,7 @@ proptest! {
             model_id: None,
             thinking_level: None,
             parent_session: None,
+            active_profile: None,
         };
         session.entries = decoded_entries;
         session._test_set_leaf_id(leaf_id);
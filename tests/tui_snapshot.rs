@@ -136,6 +136,7 @@ fn build_app_with_config(harness: &TestHarness, config: Config) -> PiApp {
         Some(KeyBindings::new()),
         Vec::new(),
         Usage::default(),
+        None,
     );
     app.set_terminal_size(80, 24);
     app
@@ -186,6 +186,7 @@ fn build_app_with_session_and_config(
         Some(KeyBindings::new()),
         messages,
         usage,
+        None,
     );
     app.set_terminal_size(80, 24);
     app
@@ -267,6 +268,7 @@ fn build_app_with_session_and_events_and_extension(
         Some(KeyBindings::new()),
         messages,
         usage,
+        None,
     );
     app.set_terminal_size(80, 24);
     (app, event_rx)
@@ -318,6 +320,7 @@ fn build_app_with_models(
         Some(keybindings),
         messages,
         usage,
+        None,
     );
     app.set_terminal_size(80, 24);
     app
@@ -390,6 +393,7 @@ fn build_app_with_session_and_events_and_config(
         Some(KeyBindings::new()),
         messages,
         usage,
+        None,
     );
     app.set_terminal_size(80, 24);
     (app, event_rx)